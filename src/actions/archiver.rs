@@ -0,0 +1,301 @@
+#[cfg(not(any(feature = "zip", feature = "tar")))]
+compile_error!("Please select a zip or tar feature to build with archiver support");
+
+use crate::actions::{Operation, Plan};
+use crate::utils::{entry_file_name, entry_relative_path, is_readable_folder, temporary_sibling};
+use crate::{EntryMetadata, FileMatcherError, FileSystem, ManyEntries, OneEntry, Result};
+use std::fs::File;
+use std::io::{BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
+
+/// Represents a format of a created archive
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum ArchiveFormat {
+    #[cfg(feature = "zip")]
+    Zip,
+    #[cfg(feature = "tar")]
+    Tar,
+    #[cfg(feature = "tar-gz")]
+    TarGz,
+}
+
+impl ArchiveFormat {
+    /// Detect a format from the extension of an archive such as `.zip` or `.tar.gz`,
+    /// returns None if the extension is unknown or its format is not enabled
+    pub fn from_path(path: impl AsRef<Path>) -> Option<Self> {
+        let file_name = path.as_ref().file_name()?.to_str()?.to_lowercase();
+
+        #[cfg(feature = "zip")]
+        if file_name.ends_with(".zip") {
+            return Some(Self::Zip);
+        }
+        #[cfg(feature = "tar")]
+        if file_name.ends_with(".tar") {
+            return Some(Self::Tar);
+        }
+        #[cfg(feature = "tar-gz")]
+        if file_name.ends_with(".tar.gz") || file_name.ends_with(".tgz") {
+            return Some(Self::TarGz);
+        }
+        None
+    }
+}
+
+/// Packs an entry into a new archive on disk. A folder is packed with all its content,
+/// the entry is named after its alias or file name within the archive.
+/// Unix permissions and modification times of the packed entries are preserved
+pub trait OneEntryArchiver {
+    fn archive(&self, archive: impl AsRef<Path>, format: ArchiveFormat) -> Result<PathBuf>;
+
+    /// Find the entry and resolve its name within the archive without creating it,
+    /// returns the operations the archiving would perform
+    fn archive_dry_run(&self, archive: impl AsRef<Path>) -> Result<Plan>;
+}
+
+/// Packs all found entries into a new archive on disk. Folders are packed with all their content,
+/// the entries are named after their paths relative to the searched folder within the archive.
+/// Unix permissions and modification times of the packed entries are preserved
+pub trait ManyEntriesArchiver {
+    fn archive(&self, archive: impl AsRef<Path>, format: ArchiveFormat) -> Result<PathBuf>;
+
+    /// Find the entries and resolve their names within the archive without creating it,
+    /// returns the operations the archiving would perform
+    fn archive_dry_run(&self, archive: impl AsRef<Path>) -> Result<Plan>;
+}
+
+impl OneEntryArchiver for OneEntry {
+    fn archive(&self, archive: impl AsRef<Path>, format: ArchiveFormat) -> Result<PathBuf> {
+        let plan = self.archive_dry_run(archive.as_ref())?;
+        write_archive(self.file_system(), archive.as_ref(), format, &plan)
+    }
+
+    fn archive_dry_run(&self, archive: impl AsRef<Path>) -> Result<Plan> {
+        let entry = self.as_path_buf()?;
+        let name = match self.entry().name_alias() {
+            Some(alias) => alias.to_string(),
+            None => entry_file_name(&entry)?.to_string(),
+        };
+
+        Ok(Plan::from(vec![Operation::Archive {
+            from: entry,
+            archive: archive.as_ref().to_path_buf(),
+            name,
+        }]))
+    }
+}
+
+impl ManyEntriesArchiver for ManyEntries {
+    fn archive(&self, archive: impl AsRef<Path>, format: ArchiveFormat) -> Result<PathBuf> {
+        let plan = self.archive_dry_run(archive.as_ref())?;
+        write_archive(self.file_system(), archive.as_ref(), format, &plan)
+    }
+
+    fn archive_dry_run(&self, archive: impl AsRef<Path>) -> Result<Plan> {
+        // entries found within nested folders keep their path relative to the searched folder,
+        // archives separate the names of nested entries with slashes on every platform
+        self.find()?
+            .into_iter()
+            .map(|entry| {
+                Ok(Operation::Archive {
+                    name: entry_relative_path(self.directory(), &entry)?
+                        .replace(std::path::MAIN_SEPARATOR, "/"),
+                    from: entry,
+                    archive: archive.as_ref().to_path_buf(),
+                })
+            })
+            .collect::<Result<Vec<Operation>>>()
+            .map(Plan::from)
+    }
+}
+
+/// Write the archive next to its destination and rename it into place once it is complete,
+/// so that a failure never leaves a partial archive behind
+fn write_archive(
+    file_system: &dyn FileSystem,
+    archive: &Path,
+    format: ArchiveFormat,
+    plan: &Plan,
+) -> Result<PathBuf> {
+    let temporary = temporary_sibling(archive);
+    let result = write_archive_entries(file_system, &temporary, format, plan)
+        .and_then(|_| Ok(std::fs::rename(&temporary, archive)?));
+    if result.is_err() {
+        // the error of the archiving is more relevant than the one of the cleanup
+        let _ = std::fs::remove_file(&temporary);
+    }
+    result.map(|_| archive.to_path_buf())
+}
+
+fn write_archive_entries(
+    file_system: &dyn FileSystem,
+    archive: &Path,
+    format: ArchiveFormat,
+    plan: &Plan,
+) -> Result<()> {
+    let mut writer = ArchiveWriter::create(archive, format)?;
+    for operation in plan {
+        match operation {
+            Operation::Archive { from, name, .. } => {
+                add_entry(&mut writer, file_system, from, name)?
+            }
+            operation => unreachable!("Archiver can not perform {}", operation),
+        }
+    }
+    writer.finish()
+}
+
+/// Add an entry to the archive under a given name, folders are added recursively
+fn add_entry(
+    writer: &mut ArchiveWriter,
+    file_system: &dyn FileSystem,
+    entry: &Path,
+    name: &str,
+) -> Result<()> {
+    let metadata = file_system.metadata(entry)?;
+
+    if metadata.is_file() {
+        writer.add_file(name, &metadata, &mut file_system.open(entry)?)
+    } else if is_readable_folder(file_system, entry) {
+        writer.add_folder(name, &metadata)?;
+        for each_entry in file_system.read_dir(entry)? {
+            let each_entry = each_entry?;
+            let each_name = format!("{}/{}", name, entry_file_name(&each_entry)?);
+            let each_metadata = file_system.metadata(&each_entry)?;
+            if each_metadata.is_file() || each_metadata.is_folder() {
+                add_entry(writer, file_system, &each_entry, &each_name)?;
+            }
+        }
+        Ok(())
+    } else {
+        FileMatcherError::NotReadable(entry.to_path_buf()).into()
+    }
+}
+
+const DEFAULT_FILE_MODE: u32 = 0o644;
+const DEFAULT_FOLDER_MODE: u32 = 0o755;
+
+enum ArchiveWriter {
+    #[cfg(feature = "zip")]
+    Zip(Box<zip::ZipWriter<BufWriter<File>>>),
+    #[cfg(feature = "tar")]
+    Tar(tar::Builder<BufWriter<File>>),
+    #[cfg(feature = "tar-gz")]
+    TarGz(tar::Builder<flate2::write::GzEncoder<BufWriter<File>>>),
+}
+
+impl ArchiveWriter {
+    fn create(archive: &Path, format: ArchiveFormat) -> Result<Self> {
+        let file = BufWriter::new(File::create(archive)?);
+        let writer = match format {
+            #[cfg(feature = "zip")]
+            ArchiveFormat::Zip => Self::Zip(Box::new(zip::ZipWriter::new(file))),
+            #[cfg(feature = "tar")]
+            ArchiveFormat::Tar => Self::Tar(tar::Builder::new(file)),
+            #[cfg(feature = "tar-gz")]
+            ArchiveFormat::TarGz => Self::TarGz(tar::Builder::new(flate2::write::GzEncoder::new(
+                file,
+                flate2::Compression::default(),
+            ))),
+        };
+        Ok(writer)
+    }
+
+    fn add_file(
+        &mut self,
+        name: &str,
+        metadata: &EntryMetadata,
+        content: &mut dyn Read,
+    ) -> Result<()> {
+        let mode = metadata.mode().unwrap_or(DEFAULT_FILE_MODE);
+        match self {
+            #[cfg(feature = "zip")]
+            Self::Zip(writer) => {
+                writer.start_file(
+                    name,
+                    zip_options(metadata, mode).large_file(metadata.len() >= u32::MAX as u64),
+                )?;
+                std::io::copy(content, writer)?;
+            }
+            #[cfg(feature = "tar")]
+            Self::Tar(builder) => append_tar_file(builder, name, metadata, mode, content)?,
+            #[cfg(feature = "tar-gz")]
+            Self::TarGz(builder) => append_tar_file(builder, name, metadata, mode, content)?,
+        }
+        Ok(())
+    }
+
+    fn add_folder(&mut self, name: &str, metadata: &EntryMetadata) -> Result<()> {
+        let mode = metadata.mode().unwrap_or(DEFAULT_FOLDER_MODE);
+        match self {
+            #[cfg(feature = "zip")]
+            Self::Zip(writer) => writer.add_directory(name, zip_options(metadata, mode))?,
+            #[cfg(feature = "tar")]
+            Self::Tar(builder) => append_tar_folder(builder, name, metadata, mode)?,
+            #[cfg(feature = "tar-gz")]
+            Self::TarGz(builder) => append_tar_folder(builder, name, metadata, mode)?,
+        }
+        Ok(())
+    }
+
+    fn finish(self) -> Result<()> {
+        match self {
+            #[cfg(feature = "zip")]
+            Self::Zip(writer) => writer.finish()?.flush()?,
+            #[cfg(feature = "tar")]
+            Self::Tar(builder) => builder.into_inner()?.flush()?,
+            #[cfg(feature = "tar-gz")]
+            Self::TarGz(builder) => builder.into_inner()?.finish()?.flush()?,
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "zip")]
+fn zip_options(metadata: &EntryMetadata, mode: u32) -> zip::write::SimpleFileOptions {
+    let options = zip::write::SimpleFileOptions::default().unix_permissions(mode);
+    match metadata
+        .modified()
+        .and_then(crate::file_system::zip_date_time)
+    {
+        Some(modified) => options.last_modified_time(modified),
+        None => options,
+    }
+}
+
+#[cfg(feature = "tar")]
+fn tar_header(metadata: &EntryMetadata, mode: u32, entry_type: tar::EntryType) -> tar::Header {
+    let mut header = tar::Header::new_gnu();
+    header.set_entry_type(entry_type);
+    header.set_mode(mode);
+    header.set_size(metadata.len());
+    if let Some(modified) = metadata.modified() {
+        if let Ok(modified) = modified.duration_since(std::time::UNIX_EPOCH) {
+            header.set_mtime(modified.as_secs());
+        }
+    }
+    header
+}
+
+#[cfg(feature = "tar")]
+fn append_tar_file<W: Write>(
+    builder: &mut tar::Builder<W>,
+    name: &str,
+    metadata: &EntryMetadata,
+    mode: u32,
+    content: &mut dyn Read,
+) -> std::io::Result<()> {
+    let mut header = tar_header(metadata, mode, tar::EntryType::Regular);
+    builder.append_data(&mut header, name, content)
+}
+
+#[cfg(feature = "tar")]
+fn append_tar_folder<W: Write>(
+    builder: &mut tar::Builder<W>,
+    name: &str,
+    metadata: &EntryMetadata,
+    mode: u32,
+) -> std::io::Result<()> {
+    let mut header = tar_header(metadata, mode, tar::EntryType::Directory);
+    header.set_size(0);
+    builder.append_data(&mut header, format!("{}/", name), std::io::empty())
+}
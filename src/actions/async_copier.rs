@@ -0,0 +1,29 @@
+#[cfg(not(all(feature = "async", feature = "copier")))]
+compile_error!("Please select async and copier features to build with async copier support");
+
+use crate::utils::spawn_blocking;
+use crate::{OneEntry, OneEntryCopier, Result};
+use std::future::Future;
+use std::path::{Path, PathBuf};
+
+/// Asynchronous version of [`OneEntryCopier`].
+/// The entry is copied on the tokio blocking thread pool, with the same semantics
+/// and errors as the blocking copy
+pub trait OneEntryAsyncCopier {
+    fn copy_async(
+        &self,
+        destination: impl AsRef<Path>,
+    ) -> impl Future<Output = Result<PathBuf>> + Send;
+}
+
+impl OneEntryAsyncCopier for OneEntry {
+    fn copy_async(
+        &self,
+        destination: impl AsRef<Path>,
+    ) -> impl Future<Output = Result<PathBuf>> + Send {
+        let entry = self.clone();
+        let destination = destination.as_ref().to_path_buf();
+
+        spawn_blocking(move || entry.copy(destination))
+    }
+}
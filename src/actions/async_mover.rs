@@ -0,0 +1,29 @@
+#[cfg(not(all(feature = "async", feature = "mover")))]
+compile_error!("Please select async and mover features to build with async mover support");
+
+use crate::utils::spawn_blocking;
+use crate::{OneEntry, OneEntryMover, Result};
+use std::future::Future;
+use std::path::{Path, PathBuf};
+
+/// Asynchronous version of [`OneEntryMover`].
+/// The entry is moved on the tokio blocking thread pool, with the same semantics
+/// and errors as the blocking move
+pub trait OneEntryAsyncMover {
+    fn move_async(
+        &self,
+        destination: impl AsRef<Path>,
+    ) -> impl Future<Output = Result<PathBuf>> + Send;
+}
+
+impl OneEntryAsyncMover for OneEntry {
+    fn move_async(
+        &self,
+        destination: impl AsRef<Path>,
+    ) -> impl Future<Output = Result<PathBuf>> + Send {
+        let entry = self.clone();
+        let destination = destination.as_ref().to_path_buf();
+
+        spawn_blocking(move || entry.r#move(destination))
+    }
+}
@@ -1,66 +1,385 @@
 #[cfg(not(feature = "copier"))]
 compile_error!("Please select a copier feature to build with copier support");
 
-use crate::{FileMatcherError, OneEntry, Result};
-use fs_extra::dir::CopyOptions;
+use crate::actions::copy_options::{copy_atomically, copy_destination, finish_copy};
+use crate::actions::plan::plan_nested_folder;
+use crate::actions::progress::{planned_size, ProgressReporter};
+use crate::actions::{CopyOptions, CopyProgress, Operation, Plan};
+use crate::file_system::{copy_file_between, copy_folder_between};
+use crate::utils::{entry_file_name, entry_relative_path, is_readable_file, is_readable_folder};
+use crate::{FileMatcherError, FileSystem, ManyEntries, OneEntry, Result};
 use std::path::{Path, PathBuf};
 
 pub trait OneEntryCopier {
     fn copy(&self, destination: impl AsRef<Path>) -> Result<PathBuf>;
+
+    /// Copy the entry to a destination within another file system,
+    /// for example to extract it from an archive
+    fn copy_to_file_system(
+        &self,
+        destination: impl AsRef<Path>,
+        file_system: &dyn FileSystem,
+    ) -> Result<PathBuf>;
+
+    /// Copy the entry reporting the progress after each copied chunk of a file.
+    /// Files are streamed through the file system instead of using a native copy
+    fn copy_with_progress(
+        &self,
+        destination: impl AsRef<Path>,
+        progress: impl FnMut(&CopyProgress),
+    ) -> Result<PathBuf>;
+
+    /// Copy the entry preserving the attributes selected by the options,
+    /// for folders the attributes of all copied entries are preserved
+    fn copy_with_options(
+        &self,
+        destination: impl AsRef<Path>,
+        options: CopyOptions,
+    ) -> Result<PathBuf>;
+
+    /// Find the entry and resolve its destination without copying it,
+    /// returns the operations the copy would perform
+    fn copy_dry_run(&self, destination: impl AsRef<Path>) -> Result<Plan>;
+}
+
+pub trait ManyEntriesCopier {
+    /// Copy all found entries inside of a destination folder creating it if necessary,
+    /// returns the paths of the copies. Entries found within nested folders of a recursive
+    /// search keep their path relative to the searched folder
+    fn copy(&self, destination: impl AsRef<Path>) -> Result<Vec<PathBuf>>;
+
+    /// Copy all found entries inside of a destination folder within another file system,
+    /// for example to extract them from an archive
+    fn copy_to_file_system(
+        &self,
+        destination: impl AsRef<Path>,
+        file_system: &dyn FileSystem,
+    ) -> Result<Vec<PathBuf>>;
+
+    /// Copy all found entries reporting the progress of the whole copy
+    /// after each copied chunk of a file
+    fn copy_with_progress(
+        &self,
+        destination: impl AsRef<Path>,
+        progress: impl FnMut(&CopyProgress),
+    ) -> Result<Vec<PathBuf>>;
+
+    /// Copy all found entries preserving the attributes selected by the options
+    fn copy_with_options(
+        &self,
+        destination: impl AsRef<Path>,
+        options: CopyOptions,
+    ) -> Result<Vec<PathBuf>>;
+
+    /// Find the entries and resolve their destinations without copying them,
+    /// returns the operations the copy would perform
+    fn copy_dry_run(&self, destination: impl AsRef<Path>) -> Result<Plan>;
 }
 
 impl OneEntryCopier for OneEntry {
     fn copy(&self, destination: impl AsRef<Path>) -> Result<PathBuf> {
-        let destination = destination.as_ref();
-        let entry = self.as_path_buf()?;
-
-        return if let Some(alias) = self.entry().name_alias() {
-            Ok(copy_entry(&entry, destination, alias)?)
-        } else if let Some(file_name) = entry.file_name() {
-            if let Some(file_name) = file_name.to_str() {
-                Ok(copy_entry(&entry, destination, file_name)?)
-            } else {
-                FileMatcherError::InvalidUnicode(file_name.to_os_string()).into()
-            }
-        } else {
-            FileMatcherError::NotReadable(entry.clone()).into()
-        };
+        copy_one_entry(
+            self,
+            destination.as_ref(),
+            Copier::within(self.file_system()),
+            None,
+        )
+    }
+
+    fn copy_to_file_system(
+        &self,
+        destination: impl AsRef<Path>,
+        file_system: &dyn FileSystem,
+    ) -> Result<PathBuf> {
+        copy_one_entry(
+            self,
+            destination.as_ref(),
+            Copier::between(self.file_system(), file_system),
+            None,
+        )
+    }
+
+    fn copy_with_progress(
+        &self,
+        destination: impl AsRef<Path>,
+        mut progress: impl FnMut(&CopyProgress),
+    ) -> Result<PathBuf> {
+        copy_one_entry(
+            self,
+            destination.as_ref(),
+            Copier::within(self.file_system()),
+            Some(&mut progress),
+        )
+    }
+
+    fn copy_with_options(
+        &self,
+        destination: impl AsRef<Path>,
+        options: CopyOptions,
+    ) -> Result<PathBuf> {
+        copy_one_entry(
+            self,
+            destination.as_ref(),
+            Copier::within(self.file_system()).with_options(options),
+            None,
+        )
+    }
+
+    fn copy_dry_run(&self, destination: impl AsRef<Path>) -> Result<Plan> {
+        plan_one_entry(
+            self,
+            destination.as_ref(),
+            Copier::within(self.file_system()),
+        )
     }
 }
 
-fn copy_entry(from: impl AsRef<Path>, to: impl AsRef<Path>, file_name: &str) -> Result<PathBuf> {
-    let from = from.as_ref();
-    if from.is_file() {
-        copy_file(from, to, file_name)
-    } else if from.is_dir() {
-        copy_folder(from, to, file_name)
-    } else {
-        FileMatcherError::NotReadable(from.to_path_buf()).into()
+impl ManyEntriesCopier for ManyEntries {
+    fn copy(&self, destination: impl AsRef<Path>) -> Result<Vec<PathBuf>> {
+        copy_many_entries(
+            self,
+            destination.as_ref(),
+            Copier::within(self.file_system()),
+            None,
+        )
+    }
+
+    fn copy_to_file_system(
+        &self,
+        destination: impl AsRef<Path>,
+        file_system: &dyn FileSystem,
+    ) -> Result<Vec<PathBuf>> {
+        copy_many_entries(
+            self,
+            destination.as_ref(),
+            Copier::between(self.file_system(), file_system),
+            None,
+        )
+    }
+
+    fn copy_with_progress(
+        &self,
+        destination: impl AsRef<Path>,
+        mut progress: impl FnMut(&CopyProgress),
+    ) -> Result<Vec<PathBuf>> {
+        copy_many_entries(
+            self,
+            destination.as_ref(),
+            Copier::within(self.file_system()),
+            Some(&mut progress),
+        )
+    }
+
+    fn copy_with_options(
+        &self,
+        destination: impl AsRef<Path>,
+        options: CopyOptions,
+    ) -> Result<Vec<PathBuf>> {
+        copy_many_entries(
+            self,
+            destination.as_ref(),
+            Copier::within(self.file_system()).with_options(options),
+            None,
+        )
+    }
+
+    fn copy_dry_run(&self, destination: impl AsRef<Path>) -> Result<Plan> {
+        plan_many_entries(
+            self,
+            destination.as_ref(),
+            Copier::within(self.file_system()),
+        )
     }
 }
 
-fn copy_file(from: impl AsRef<Path>, to: impl AsRef<Path>, file_name: &str) -> Result<PathBuf> {
-    let destination = to.as_ref();
+fn plan_one_entry(entry: &OneEntry, destination: &Path, copier: Copier) -> Result<Plan> {
+    let path = entry.as_path_buf()?;
 
-    let destination = if destination.is_dir() {
-        destination.join(file_name)
+    let operation = if let Some(alias) = entry.entry().name_alias() {
+        copier.plan_entry(&path, destination, alias, false)?
     } else {
-        destination.to_path_buf()
+        copier.plan_entry(&path, destination, entry_file_name(&path)?, false)?
     };
+    Ok(Plan::from(vec![operation]))
+}
+
+fn plan_many_entries(entries: &ManyEntries, destination: &Path, copier: Copier) -> Result<Plan> {
+    let paths = entries.find()?;
 
-    std::fs::copy(&from, &destination)?;
-    Ok(destination)
+    // entries found within nested folders are copied into the same nested folders
+    let mut operations = vec![Operation::CreateFolder(destination.to_path_buf())];
+    for path in &paths {
+        let relative = entry_relative_path(entries.directory(), path)?;
+        plan_nested_folder(&mut operations, destination, relative);
+        operations.push(copier.plan_entry(path, destination, relative, true)?);
+    }
+    Ok(Plan::from(operations))
 }
 
-fn copy_folder(from: impl AsRef<Path>, to: impl AsRef<Path>, file_name: &str) -> Result<PathBuf> {
-    let destination = to.as_ref();
+fn copy_one_entry(
+    entry: &OneEntry,
+    destination: &Path,
+    copier: Copier,
+    progress: Option<&mut dyn FnMut(&CopyProgress)>,
+) -> Result<PathBuf> {
+    let plan = plan_one_entry(entry, destination, copier)?;
+    let mut reporter = copier.reporter(&plan, progress)?;
 
-    let destination = destination.join(file_name);
+    let mut copied = destination.to_path_buf();
+    for operation in &plan {
+        copier.execute(operation, reporter.as_mut())?;
+        copied = operation.destination().to_path_buf();
+    }
+    Ok(copied)
+}
 
-    let mut options = CopyOptions::new();
-    options.copy_inside = true;
+fn copy_many_entries(
+    entries: &ManyEntries,
+    destination: &Path,
+    copier: Copier,
+    progress: Option<&mut dyn FnMut(&CopyProgress)>,
+) -> Result<Vec<PathBuf>> {
+    let plan = plan_many_entries(entries, destination, copier)?;
+    let mut reporter = copier.reporter(&plan, progress)?;
 
-    fs_extra::dir::copy(&from, &destination, &options).map_err(FileMatcherError::FsExtraError)?;
+    let mut copies = vec![];
+    for operation in &plan {
+        copier.execute(operation, reporter.as_mut())?;
+        if let Operation::CopyFile { to, .. } | Operation::CopyFolder { to, .. } = operation {
+            copies.push(to.clone());
+        }
+    }
+    Ok(copies)
+}
+
+/// Perform a single planned copy operation within a file system
+#[cfg(any(feature = "transaction", feature = "syncer"))]
+pub(crate) fn execute_copy(file_system: &dyn FileSystem, operation: &Operation) -> Result<()> {
+    Copier::within(file_system).execute(operation, None)
+}
+
+/// Copies entries within a file system or from one file system to another
+#[derive(Clone, Copy)]
+struct Copier<'a> {
+    source: &'a dyn FileSystem,
+    target: Option<&'a dyn FileSystem>,
+    options: CopyOptions,
+}
+
+impl<'a> Copier<'a> {
+    fn within(file_system: &'a dyn FileSystem) -> Self {
+        Self {
+            source: file_system,
+            target: None,
+            options: CopyOptions::new(),
+        }
+    }
+
+    fn between(source: &'a dyn FileSystem, target: &'a dyn FileSystem) -> Self {
+        Self {
+            source,
+            target: Some(target),
+            options: CopyOptions::new(),
+        }
+    }
+
+    fn with_options(mut self, options: CopyOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    fn target(&self) -> &'a dyn FileSystem {
+        self.target.unwrap_or(self.source)
+    }
 
-    Ok(destination)
+    /// Resolve the destination of an entry copied to a given path. A file copied to a folder
+    /// is named `file_name` inside of it, a folder is always copied inside `to` as `file_name`.
+    /// `to` is known to be a folder when it is created before the copy
+    fn plan_entry(
+        &self,
+        from: &Path,
+        to: &Path,
+        file_name: &str,
+        to_is_folder: bool,
+    ) -> Result<Operation> {
+        let from = from.to_path_buf();
+        if is_readable_file(self.source, &from) {
+            let to = if to_is_folder || self.target().resolves_to_folder(to) {
+                to.join(file_name)
+            } else {
+                to.to_path_buf()
+            };
+            Ok(Operation::CopyFile { from, to })
+        } else if is_readable_folder(self.source, &from) {
+            let to = to.join(file_name);
+            Ok(Operation::CopyFolder { from, to })
+        } else {
+            FileMatcherError::NotReadable(from).into()
+        }
+    }
+
+    fn reporter<'p>(
+        &self,
+        plan: &Plan,
+        progress: Option<&'p mut dyn FnMut(&CopyProgress)>,
+    ) -> Result<Option<ProgressReporter<'p>>> {
+        match progress {
+            None => Ok(None),
+            Some(progress) => Ok(Some(ProgressReporter::new(
+                planned_size(self.source, plan)?,
+                progress,
+            ))),
+        }
+    }
+
+    fn execute(
+        &self,
+        operation: &Operation,
+        reporter: Option<&mut ProgressReporter>,
+    ) -> Result<()> {
+        let (from, to) = match operation {
+            Operation::CreateFolder(path) => return Ok(self.target().create_dir_all(path)?),
+            Operation::CopyFile { from, to } | Operation::CopyFolder { from, to } => (from, to),
+            operation => unreachable!("Copier can not perform {}", operation),
+        };
+        let copy = copy_destination(self.target(), operation).unwrap_or_else(|| to.clone());
+
+        if self.options.is_atomic() {
+            copy_atomically(self.target(), &copy, |temporary| {
+                self.copy_entry(operation, from, temporary, reporter)?;
+                finish_copy(self.source, from, self.target(), temporary, self.options)
+            })
+        } else {
+            self.copy_entry(operation, from, to, reporter)?;
+            finish_copy(self.source, from, self.target(), &copy, self.options)
+        }
+    }
+
+    /// Copy a file or a folder of the operation from `from` to `to`
+    fn copy_entry(
+        &self,
+        operation: &Operation,
+        from: &Path,
+        to: &Path,
+        reporter: Option<&mut ProgressReporter>,
+    ) -> Result<()> {
+        let is_file = matches!(operation, Operation::CopyFile { .. });
+        match (is_file, reporter, self.target) {
+            (true, Some(reporter), _) => {
+                reporter.copy_file(self.source, from, self.target(), to)?;
+            }
+            (true, None, None) => {
+                self.source.copy_file(from, to)?;
+            }
+            (true, None, Some(target)) => {
+                copy_file_between(self.source, from, target, to)?;
+            }
+            (false, Some(reporter), _) => {
+                reporter.copy_folder(self.source, from, self.target(), to)?
+            }
+            (false, None, None) => self.source.copy_folder(from, to)?,
+            (false, None, Some(target)) => copy_folder_between(self.source, from, target, to)?,
+        }
+        Ok(())
+    }
 }
@@ -0,0 +1,231 @@
+use crate::actions::Operation;
+#[cfg(any(feature = "sha256", feature = "blake3"))]
+use crate::checksum::{verify_copy, Checksum};
+use crate::utils::temporary_sibling;
+use crate::{FileSystem, Result};
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+/// Describes which attributes of the copied files and folders are preserved
+/// and whether the copies are verified. By default only the content is copied
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub struct CopyOptions {
+    times: bool,
+    permissions: bool,
+    ownership: bool,
+    xattrs: bool,
+    atomic: bool,
+    #[cfg(any(feature = "sha256", feature = "blake3"))]
+    checksum: Option<Checksum>,
+}
+
+impl CopyOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Preserve times, permissions, ownership and extended attributes
+    pub fn preserve_all() -> Self {
+        Self::new()
+            .preserve_times()
+            .preserve_permissions()
+            .preserve_ownership()
+            .preserve_xattrs()
+    }
+
+    /// Preserve the access and modification times
+    pub fn preserve_times(mut self) -> Self {
+        self.times = true;
+        self
+    }
+
+    /// Preserve the Unix permission bits
+    pub fn preserve_permissions(mut self) -> Self {
+        self.permissions = true;
+        self
+    }
+
+    /// Preserve the owning user and group where the process is allowed to change them
+    pub fn preserve_ownership(mut self) -> Self {
+        self.ownership = true;
+        self
+    }
+
+    /// Preserve the extended attributes where the target file system supports them
+    pub fn preserve_xattrs(mut self) -> Self {
+        self.xattrs = true;
+        self
+    }
+
+    /// Copy to a temporary sibling of the destination and rename it into place once the copy
+    /// succeeds, so that an interrupted copy never leaves a partial entry under the final name.
+    /// The temporary copy is removed if the copy fails
+    pub fn atomic(mut self) -> Self {
+        self.atomic = true;
+        self
+    }
+
+    /// Compare the checksums of the copied files and their copies after the copy,
+    /// a mismatch fails with [`crate::FileMatcherError::ChecksumMismatch`]
+    #[cfg(any(feature = "sha256", feature = "blake3"))]
+    pub fn verify(mut self, checksum: Checksum) -> Self {
+        self.checksum = Some(checksum);
+        self
+    }
+
+    #[cfg(any(feature = "sha256", feature = "blake3"))]
+    pub fn checksum(&self) -> Option<Checksum> {
+        self.checksum
+    }
+
+    pub fn preserves_times(&self) -> bool {
+        self.times
+    }
+
+    pub fn preserves_permissions(&self) -> bool {
+        self.permissions
+    }
+
+    pub fn preserves_ownership(&self) -> bool {
+        self.ownership
+    }
+
+    pub fn preserves_xattrs(&self) -> bool {
+        self.xattrs
+    }
+
+    pub fn is_atomic(&self) -> bool {
+        self.atomic
+    }
+
+    fn preserves_any(&self) -> bool {
+        self.times || self.permissions || self.ownership || self.xattrs
+    }
+}
+
+/// Return where a copy or move operation places the entry, must be resolved before the operation.
+/// A folder is copied inside of an existing destination
+pub(crate) fn copy_destination(
+    to_file_system: &dyn FileSystem,
+    operation: &Operation,
+) -> Option<PathBuf> {
+    match operation {
+        Operation::CopyFolder { from, to } | Operation::MoveFolder { from, to }
+            if to_file_system.metadata(to).is_ok() =>
+        {
+            from.file_name().map(|folder_name| to.join(folder_name))
+        }
+        Operation::CopyFile { to, .. }
+        | Operation::CopyFolder { to, .. }
+        | Operation::MoveFile { to, .. }
+        | Operation::MoveFolder { to, .. } => Some(to.clone()),
+        _ => None,
+    }
+}
+
+/// Copy an entry to a temporary sibling of `to` and rename it to `to` once `copy` succeeds,
+/// the temporary copy is removed when any of the steps fails
+pub(crate) fn copy_atomically(
+    to_file_system: &dyn FileSystem,
+    to: &Path,
+    copy: impl FnOnce(&Path) -> Result<()>,
+) -> Result<()> {
+    let temporary = temporary_sibling(to);
+    let result = copy(&temporary).and_then(|_| Ok(to_file_system.rename(&temporary, to)?));
+
+    if result.is_err() {
+        if let Ok(metadata) = to_file_system.metadata(&temporary) {
+            // the error of the copy is more relevant than the one of the cleanup
+            let _ = if metadata.is_folder() {
+                to_file_system.remove_dir_all(&temporary)
+            } else {
+                to_file_system.remove_file(&temporary)
+            };
+        }
+    }
+    result
+}
+
+/// Verify the copy of an entry and preserve its attributes as selected by the options
+pub(crate) fn finish_copy(
+    from_file_system: &dyn FileSystem,
+    from: &Path,
+    to_file_system: &dyn FileSystem,
+    to: &Path,
+    options: CopyOptions,
+) -> Result<()> {
+    #[cfg(any(feature = "sha256", feature = "blake3"))]
+    if let Some(checksum) = options.checksum {
+        verify_copy(checksum, from_file_system, from, to_file_system, to)?;
+    }
+    preserve_attributes(from_file_system, from, to_file_system, to, options)
+}
+
+/// Apply the attributes of a copied entry to its copy, folders are processed recursively.
+/// The times of a folder are applied after its content so that they are not changed again
+fn preserve_attributes(
+    from_file_system: &dyn FileSystem,
+    from: &Path,
+    to_file_system: &dyn FileSystem,
+    to: &Path,
+    options: CopyOptions,
+) -> Result<()> {
+    if !options.preserves_any() {
+        return Ok(());
+    }
+
+    let metadata = from_file_system.metadata(from)?;
+
+    if metadata.is_folder() {
+        for each_entry in from_file_system.read_dir(from)? {
+            let each_entry = each_entry?;
+            if let Some(each_name) = each_entry.file_name() {
+                let each_copy = to.join(each_name);
+                // entries that are not copied, such as symbolic links, have no copy
+                if to_file_system.metadata(&each_copy).is_ok() {
+                    preserve_attributes(
+                        from_file_system,
+                        &each_entry,
+                        to_file_system,
+                        &each_copy,
+                        options,
+                    )?;
+                }
+            }
+        }
+    }
+
+    if options.xattrs {
+        for (name, value) in from_file_system.xattrs(from)? {
+            where_possible(to_file_system.set_xattr(to, &name, &value))?;
+        }
+    }
+    // changing the owner may reset special permission bits, so it goes first
+    if options.ownership {
+        if let Some((uid, gid)) = metadata.owner() {
+            where_possible(to_file_system.set_owner(to, uid, gid))?;
+        }
+    }
+    if options.permissions {
+        if let Some(mode) = metadata.mode() {
+            to_file_system.set_mode(to, mode)?;
+        }
+    }
+    if options.times && (metadata.accessed().is_some() || metadata.modified().is_some()) {
+        to_file_system.set_times(to, metadata.accessed(), metadata.modified())?;
+    }
+    Ok(())
+}
+
+/// Ignore the errors of the operations that are not permitted or not supported
+pub(crate) fn where_possible(result: std::io::Result<()>) -> std::io::Result<()> {
+    match result {
+        Err(error)
+            if error.kind() == ErrorKind::PermissionDenied
+                || error.kind() == ErrorKind::Unsupported =>
+        {
+            Ok(())
+        }
+        result => result,
+    }
+}
@@ -0,0 +1,258 @@
+#[cfg(not(feature = "linker"))]
+compile_error!("Please select a linker feature to build with linker support");
+
+use crate::actions::plan::plan_nested_folder;
+use crate::actions::{Operation, Plan};
+use crate::utils::{entry_file_name, entry_relative_path, relative_path};
+use crate::{FileSystem, ManyEntries, OneEntry, Result};
+use std::io::{Error, ErrorKind};
+use std::path::{Path, PathBuf};
+
+/// The kind of a link to create to an entry
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LinkKind {
+    /// A symbolic link relative to the link folder when possible
+    Symbolic,
+    /// A hard link, only supported for files
+    Hard,
+}
+
+/// What to do when the destination of an action already exists
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OverwritePolicy {
+    /// Fail with an error
+    #[default]
+    Fail,
+    /// Replace the existing file or link, an existing folder is never replaced
+    Replace,
+    /// Leave the existing entry untouched
+    Skip,
+}
+
+pub trait OneEntryLinker {
+    /// Create a symbolic link to the entry inside of a destination folder under the alias
+    /// or the entry name, or at the destination path if it is not an existing folder.
+    /// Fails if the link already exists. Returns the path of the link
+    fn symlink(&self, destination: impl AsRef<Path>) -> Result<PathBuf> {
+        self.link(destination, LinkKind::Symbolic, OverwritePolicy::Fail)
+    }
+
+    /// Create a hard link to the entry with the same destination rules as [`OneEntryLinker::symlink`]
+    fn hard_link(&self, destination: impl AsRef<Path>) -> Result<PathBuf> {
+        self.link(destination, LinkKind::Hard, OverwritePolicy::Fail)
+    }
+
+    /// Create a link of a given kind, resolving an existing destination with the overwrite policy
+    fn link(
+        &self,
+        destination: impl AsRef<Path>,
+        kind: LinkKind,
+        overwrite: OverwritePolicy,
+    ) -> Result<PathBuf>;
+
+    /// Find the entry and resolve the link without creating it,
+    /// returns the operations [`OneEntryLinker::link`] would perform
+    fn link_dry_run(
+        &self,
+        destination: impl AsRef<Path>,
+        kind: LinkKind,
+        overwrite: OverwritePolicy,
+    ) -> Result<Plan>;
+}
+
+pub trait ManyEntriesLinker {
+    /// Create symbolic links to all found entries inside of a destination folder
+    /// creating it if necessary, returns the paths of the links. Entries found within
+    /// nested folders of a recursive search keep their path relative to the searched folder
+    fn symlink(&self, destination: impl AsRef<Path>) -> Result<Vec<PathBuf>> {
+        self.link(destination, LinkKind::Symbolic, OverwritePolicy::Fail)
+    }
+
+    /// Create hard links to all found entries inside of a destination folder
+    /// creating it if necessary, returns the paths of the links
+    fn hard_link(&self, destination: impl AsRef<Path>) -> Result<Vec<PathBuf>> {
+        self.link(destination, LinkKind::Hard, OverwritePolicy::Fail)
+    }
+
+    /// Create links of a given kind, resolving existing links with the overwrite policy.
+    /// Returns the paths of the created links, existing links skipped by the policy are left out
+    fn link(
+        &self,
+        destination: impl AsRef<Path>,
+        kind: LinkKind,
+        overwrite: OverwritePolicy,
+    ) -> Result<Vec<PathBuf>>;
+
+    /// Find the entries and resolve the links without creating them,
+    /// returns the operations [`ManyEntriesLinker::link`] would perform
+    fn link_dry_run(
+        &self,
+        destination: impl AsRef<Path>,
+        kind: LinkKind,
+        overwrite: OverwritePolicy,
+    ) -> Result<Plan>;
+}
+
+impl OneEntryLinker for OneEntry {
+    fn link(
+        &self,
+        destination: impl AsRef<Path>,
+        kind: LinkKind,
+        overwrite: OverwritePolicy,
+    ) -> Result<PathBuf> {
+        let link = link_path(self, destination.as_ref())?;
+        let plan = plan_link(
+            self.file_system(),
+            &self.as_path_buf()?,
+            &link,
+            kind,
+            overwrite,
+        )?;
+        execute(self.file_system(), &plan)?;
+        Ok(link)
+    }
+
+    fn link_dry_run(
+        &self,
+        destination: impl AsRef<Path>,
+        kind: LinkKind,
+        overwrite: OverwritePolicy,
+    ) -> Result<Plan> {
+        let link = link_path(self, destination.as_ref())?;
+        plan_link(
+            self.file_system(),
+            &self.as_path_buf()?,
+            &link,
+            kind,
+            overwrite,
+        )
+    }
+}
+
+/// Resolve where the link to the entry is created
+fn link_path(entry: &OneEntry, destination: &Path) -> Result<PathBuf> {
+    if entry.file_system().resolves_to_folder(destination) {
+        match entry.entry().name_alias() {
+            Some(alias) => Ok(destination.join(alias)),
+            None => Ok(destination.join(entry_file_name(&entry.as_path_buf()?)?)),
+        }
+    } else {
+        Ok(destination.to_path_buf())
+    }
+}
+
+impl ManyEntriesLinker for ManyEntries {
+    fn link(
+        &self,
+        destination: impl AsRef<Path>,
+        kind: LinkKind,
+        overwrite: OverwritePolicy,
+    ) -> Result<Vec<PathBuf>> {
+        let plan = plan_many_links(self, destination.as_ref(), kind, overwrite)?;
+        execute(self.file_system(), &plan)?;
+
+        // links skipped by the overwrite policy are not part of the plan
+        Ok(plan
+            .into_iter()
+            .filter_map(|operation| match operation {
+                Operation::Symlink { link, .. } | Operation::HardLink { link, .. } => Some(link),
+                _ => None,
+            })
+            .collect())
+    }
+
+    fn link_dry_run(
+        &self,
+        destination: impl AsRef<Path>,
+        kind: LinkKind,
+        overwrite: OverwritePolicy,
+    ) -> Result<Plan> {
+        plan_many_links(self, destination.as_ref(), kind, overwrite)
+    }
+}
+
+/// Entries found within nested folders are linked within the same nested folders
+fn plan_many_links(
+    entries: &ManyEntries,
+    destination: &Path,
+    kind: LinkKind,
+    overwrite: OverwritePolicy,
+) -> Result<Plan> {
+    let mut operations = vec![Operation::CreateFolder(destination.to_path_buf())];
+    for entry in entries.find()? {
+        let relative = entry_relative_path(entries.directory(), &entry)?;
+        plan_nested_folder(&mut operations, destination, relative);
+        let link = destination.join(relative);
+        operations.extend(plan_link(
+            entries.file_system(),
+            &entry,
+            &link,
+            kind,
+            overwrite,
+        )?);
+    }
+    Ok(Plan::from(operations))
+}
+
+/// An existing link is left untouched when skipped, so nothing is planned for it
+fn plan_link(
+    file_system: &dyn FileSystem,
+    entry: &Path,
+    link: &Path,
+    kind: LinkKind,
+    overwrite: OverwritePolicy,
+) -> Result<Plan> {
+    let mut operations = vec![];
+    if let Ok(metadata) = file_system.metadata(link) {
+        match overwrite {
+            OverwritePolicy::Skip => return Ok(Plan::new()),
+            OverwritePolicy::Replace if !metadata.is_folder() => {
+                operations.push(Operation::RemoveFile(link.to_path_buf()))
+            }
+            _ => {
+                return Err(Error::new(
+                    ErrorKind::AlreadyExists,
+                    format!("{:?} already exists", link),
+                )
+                .into())
+            }
+        }
+    }
+
+    let link = link.to_path_buf();
+    operations.push(match kind {
+        LinkKind::Symbolic => Operation::Symlink {
+            target: symlink_target(entry, &link)?,
+            link,
+        },
+        LinkKind::Hard => Operation::HardLink {
+            original: entry.to_path_buf(),
+            link,
+        },
+    });
+    Ok(Plan::from(operations))
+}
+
+fn execute(file_system: &dyn FileSystem, plan: &Plan) -> Result<()> {
+    for operation in plan {
+        match operation {
+            Operation::CreateFolder(path) => file_system.create_dir_all(path)?,
+            Operation::RemoveFile(path) => file_system.remove_file(path)?,
+            Operation::Symlink { target, link } => file_system.symlink(target, link)?,
+            Operation::HardLink { original, link } => file_system.hard_link(original, link)?,
+            operation => unreachable!("Linker can not perform {}", operation),
+        }
+    }
+    Ok(())
+}
+
+/// Symbolic links point to the entry relative to the folder of the link,
+/// so that they stay valid when both are moved together
+fn symlink_target(entry: &Path, link: &Path) -> Result<PathBuf> {
+    let link_folder = link.parent().unwrap_or_else(|| Path::new(""));
+    match relative_path(link_folder, entry) {
+        Some(target) => Ok(target),
+        None if entry.is_absolute() => Ok(entry.to_path_buf()),
+        None => Ok(std::env::current_dir()?.join(entry)),
+    }
+}
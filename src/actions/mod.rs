@@ -1,9 +1,73 @@
+#[cfg(any(
+    feature = "copier",
+    feature = "mover",
+    feature = "remover",
+    feature = "linker",
+    feature = "zip",
+    feature = "tar"
+))]
+mod plan;
+#[cfg(any(
+    feature = "copier",
+    feature = "mover",
+    feature = "remover",
+    feature = "linker",
+    feature = "zip",
+    feature = "tar"
+))]
+pub use plan::{Operation, Plan};
+
+#[cfg(any(feature = "copier", feature = "mover"))]
+mod progress;
+#[cfg(any(feature = "copier", feature = "mover"))]
+pub use progress::CopyProgress;
+
+#[cfg(any(feature = "copier", feature = "mover"))]
+mod copy_options;
+#[cfg(any(feature = "copier", feature = "mover"))]
+pub use copy_options::CopyOptions;
+
 #[cfg(feature = "copier")]
 mod copier;
 #[cfg(feature = "copier")]
-pub use copier::OneEntryCopier;
+pub use copier::{ManyEntriesCopier, OneEntryCopier};
 
 #[cfg(feature = "mover")]
 mod mover;
 #[cfg(feature = "mover")]
 pub use mover::OneEntryMover;
+
+#[cfg(all(feature = "async", feature = "copier"))]
+mod async_copier;
+#[cfg(all(feature = "async", feature = "copier"))]
+pub use async_copier::OneEntryAsyncCopier;
+
+#[cfg(all(feature = "async", feature = "mover"))]
+mod async_mover;
+#[cfg(all(feature = "async", feature = "mover"))]
+pub use async_mover::OneEntryAsyncMover;
+
+#[cfg(any(feature = "zip", feature = "tar"))]
+mod archiver;
+#[cfg(any(feature = "zip", feature = "tar"))]
+pub use archiver::{ArchiveFormat, ManyEntriesArchiver, OneEntryArchiver};
+
+#[cfg(feature = "remover")]
+mod remover;
+#[cfg(feature = "remover")]
+pub use remover::{ManyEntriesRemover, OneEntryRemover};
+
+#[cfg(feature = "linker")]
+mod linker;
+#[cfg(feature = "linker")]
+pub use linker::{LinkKind, ManyEntriesLinker, OneEntryLinker, OverwritePolicy};
+
+#[cfg(feature = "transaction")]
+mod transaction;
+#[cfg(feature = "transaction")]
+pub use transaction::Transaction;
+
+#[cfg(feature = "syncer")]
+mod syncer;
+#[cfg(feature = "syncer")]
+pub use syncer::{ManyEntriesSyncer, OneEntrySyncer, SyncComparison, SyncOptions, SyncSummary};
@@ -1,70 +1,179 @@
 #[cfg(not(feature = "mover"))]
 compile_error!("Please select a mover feature to build with mover support");
 
+use crate::actions::copy_options::{copy_atomically, copy_destination, finish_copy};
+use crate::actions::progress::{planned_size, ProgressReporter};
+use crate::actions::{CopyOptions, CopyProgress, Operation, Plan};
+use crate::utils::{entry_file_name, is_readable_file, is_readable_folder};
 use crate::Result;
-use crate::{FileMatcherError, OneEntry};
-use fs_extra::dir::CopyOptions;
+use crate::{FileMatcherError, FileSystem, OneEntry};
 use std::path::{Path, PathBuf};
 
 pub trait OneEntryMover {
     fn r#move(&self, destination: impl AsRef<Path>) -> Result<PathBuf>;
+
+    /// Move the entry reporting the progress after each copied chunk of a file.
+    /// Files are streamed through the file system instead of using a native copy
+    fn move_with_progress(
+        &self,
+        destination: impl AsRef<Path>,
+        progress: impl FnMut(&CopyProgress),
+    ) -> Result<PathBuf>;
+
+    /// Move the entry preserving the attributes selected by the options.
+    /// When the options verify the copy, the entry is removed only after its copy is verified
+    fn move_with_options(
+        &self,
+        destination: impl AsRef<Path>,
+        options: CopyOptions,
+    ) -> Result<PathBuf>;
+
+    /// Find the entry and resolve its destination without moving it,
+    /// returns the operations the move would perform
+    fn move_dry_run(&self, destination: impl AsRef<Path>) -> Result<Plan>;
 }
 
 impl OneEntryMover for OneEntry {
     fn r#move(&self, destination: impl AsRef<Path>) -> Result<PathBuf> {
+        move_entry(self, destination.as_ref(), None, CopyOptions::new())
+    }
+
+    fn move_with_progress(
+        &self,
+        destination: impl AsRef<Path>,
+        mut progress: impl FnMut(&CopyProgress),
+    ) -> Result<PathBuf> {
+        move_entry(
+            self,
+            destination.as_ref(),
+            Some(&mut progress),
+            CopyOptions::new(),
+        )
+    }
+
+    fn move_with_options(
+        &self,
+        destination: impl AsRef<Path>,
+        options: CopyOptions,
+    ) -> Result<PathBuf> {
+        move_entry(self, destination.as_ref(), None, options)
+    }
+
+    fn move_dry_run(&self, destination: impl AsRef<Path>) -> Result<Plan> {
         let destination = destination.as_ref();
         let file = self.as_path_buf()?;
+        let file_system = self.file_system();
 
-        return if let Some(alias) = self.entry().name_alias() {
-            Ok(move_entry(&file, destination, alias)?)
-        } else if let Some(file_name) = file.file_name() {
-            if let Some(file_name) = file_name.to_str() {
-                Ok(move_entry(&file, destination, file_name)?)
-            } else {
-                FileMatcherError::InvalidUnicode(file_name.to_os_string()).into()
-            }
-        } else {
-            FileMatcherError::NotReadable(file.clone()).into()
+        let operation = match self.entry().name_alias() {
+            Some(alias) => plan_entry(file_system, &file, destination, alias)?,
+            None => plan_entry(file_system, &file, destination, entry_file_name(&file)?)?,
         };
+        Ok(Plan::from(vec![operation]))
     }
 }
 
-fn move_entry(from: impl AsRef<Path>, to: impl AsRef<Path>, file_name: &str) -> Result<PathBuf> {
-    let from = from.as_ref();
-    if from.is_file() {
-        move_file(from, to, file_name)
-    } else if from.is_dir() {
-        move_folder(from, to, file_name)
-    } else {
-        FileMatcherError::NotReadable(from.to_path_buf()).into()
+fn move_entry(
+    entry: &OneEntry,
+    destination: &Path,
+    progress: Option<&mut dyn FnMut(&CopyProgress)>,
+    options: CopyOptions,
+) -> Result<PathBuf> {
+    let file_system = entry.file_system();
+    let plan = entry.move_dry_run(destination)?;
+    let mut reporter = match progress {
+        None => None,
+        Some(progress) => Some(ProgressReporter::new(
+            planned_size(file_system, &plan)?,
+            progress,
+        )),
+    };
+
+    let mut moved = destination.to_path_buf();
+    for operation in &plan {
+        execute(file_system, operation, reporter.as_mut(), options)?;
+        moved = operation.destination().to_path_buf();
     }
+    Ok(moved)
 }
 
-fn move_file(from: impl AsRef<Path>, to: impl AsRef<Path>, file_name: &str) -> Result<PathBuf> {
-    let destination = to.as_ref();
-
-    let destination = if destination.is_dir() {
-        destination.join(file_name)
+/// Resolve the destination of a moved entry. A file moved to an existing folder
+/// is named `file_name` inside of it, a folder is always moved inside `to` as `file_name`
+fn plan_entry(
+    file_system: &dyn FileSystem,
+    from: &Path,
+    to: &Path,
+    file_name: &str,
+) -> Result<Operation> {
+    let from = from.to_path_buf();
+    if is_readable_file(file_system, &from) {
+        let to = if file_system.resolves_to_folder(to) {
+            to.join(file_name)
+        } else {
+            to.to_path_buf()
+        };
+        Ok(Operation::MoveFile { from, to })
+    } else if is_readable_folder(file_system, &from) {
+        let to = to.join(file_name);
+        Ok(Operation::MoveFolder { from, to })
     } else {
-        destination.to_path_buf()
-    };
-
-    std::fs::copy(&from, &destination)?;
-    std::fs::remove_file(&from)?;
-    Ok(destination)
+        FileMatcherError::NotReadable(from).into()
+    }
 }
 
-fn move_folder(from: impl AsRef<Path>, to: impl AsRef<Path>, file_name: &str) -> Result<PathBuf> {
-    let destination = to.as_ref();
-
-    let destination = destination.join(file_name);
+/// Perform a single planned move operation within a file system
+#[cfg(feature = "transaction")]
+pub(crate) fn execute_move(file_system: &dyn FileSystem, operation: &Operation) -> Result<()> {
+    execute(file_system, operation, None, CopyOptions::new())
+}
 
-    let mut options = CopyOptions::new();
-    options.copy_inside = true;
+fn execute(
+    file_system: &dyn FileSystem,
+    operation: &Operation,
+    reporter: Option<&mut ProgressReporter>,
+    options: CopyOptions,
+) -> Result<()> {
+    let (from, to) = match operation {
+        Operation::MoveFile { from, to } | Operation::MoveFolder { from, to } => (from, to),
+        operation => unreachable!("Mover can not perform {}", operation),
+    };
+    let copy = copy_destination(file_system, operation).unwrap_or_else(|| to.clone());
 
-    fs_extra::dir::copy(&from, &destination, &options).map_err(FileMatcherError::FsExtraError)?;
+    if options.is_atomic() {
+        copy_atomically(file_system, &copy, |temporary| {
+            copy_entry(file_system, operation, from, temporary, reporter)?;
+            finish_copy(file_system, from, file_system, temporary, options)
+        })?;
+    } else {
+        copy_entry(file_system, operation, from, to, reporter)?;
+        finish_copy(file_system, from, file_system, &copy, options)?;
+    }
 
-    std::fs::remove_dir_all(&from)?;
+    match operation {
+        Operation::MoveFolder { .. } => file_system.remove_dir_all(from)?,
+        _ => file_system.remove_file(from)?,
+    }
+    Ok(())
+}
 
-    Ok(destination)
+/// Copy a moved file or folder from `from` to `to` before the original is removed
+fn copy_entry(
+    file_system: &dyn FileSystem,
+    operation: &Operation,
+    from: &Path,
+    to: &Path,
+    reporter: Option<&mut ProgressReporter>,
+) -> Result<()> {
+    match (operation, reporter) {
+        (Operation::MoveFolder { .. }, Some(reporter)) => {
+            reporter.copy_folder(file_system, from, file_system, to)?
+        }
+        (Operation::MoveFolder { .. }, None) => file_system.copy_folder(from, to)?,
+        (_, Some(reporter)) => {
+            reporter.copy_file(file_system, from, file_system, to)?;
+        }
+        (_, None) => {
+            file_system.copy_file(from, to)?;
+        }
+    }
+    Ok(())
 }
@@ -0,0 +1,163 @@
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+/// A single file system operation performed by an action
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Operation {
+    CreateFolder(PathBuf),
+    CopyFile {
+        from: PathBuf,
+        to: PathBuf,
+    },
+    CopyFolder {
+        from: PathBuf,
+        to: PathBuf,
+    },
+    MoveFile {
+        from: PathBuf,
+        to: PathBuf,
+    },
+    MoveFolder {
+        from: PathBuf,
+        to: PathBuf,
+    },
+    RemoveFile(PathBuf),
+    /// Remove a folder with all its content
+    RemoveFolder(PathBuf),
+    /// Create a symbolic link pointing to a target relative to the folder of the link
+    Symlink {
+        target: PathBuf,
+        link: PathBuf,
+    },
+    HardLink {
+        original: PathBuf,
+        link: PathBuf,
+    },
+    /// Pack an entry into an archive under a given name
+    Archive {
+        from: PathBuf,
+        archive: PathBuf,
+        name: String,
+    },
+}
+
+impl Operation {
+    /// Return the path created, changed or removed by the operation
+    pub fn destination(&self) -> &Path {
+        match self {
+            Operation::CreateFolder(path)
+            | Operation::RemoveFile(path)
+            | Operation::RemoveFolder(path) => path,
+            Operation::CopyFile { to, .. }
+            | Operation::CopyFolder { to, .. }
+            | Operation::MoveFile { to, .. }
+            | Operation::MoveFolder { to, .. } => to,
+            Operation::Symlink { link, .. } | Operation::HardLink { link, .. } => link,
+            Operation::Archive { archive, .. } => archive,
+        }
+    }
+}
+
+impl fmt::Display for Operation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Operation::CreateFolder(path) => write!(f, "create folder {:?}", path),
+            Operation::CopyFile { from, to } => write!(f, "copy file {:?} to {:?}", from, to),
+            Operation::CopyFolder { from, to } => write!(f, "copy folder {:?} to {:?}", from, to),
+            Operation::MoveFile { from, to } => write!(f, "move file {:?} to {:?}", from, to),
+            Operation::MoveFolder { from, to } => write!(f, "move folder {:?} to {:?}", from, to),
+            Operation::RemoveFile(path) => write!(f, "remove file {:?}", path),
+            Operation::RemoveFolder(path) => write!(f, "remove folder {:?}", path),
+            Operation::Symlink { target, link } => {
+                write!(f, "create symbolic link {:?} to {:?}", link, target)
+            }
+            Operation::HardLink { original, link } => {
+                write!(f, "create hard link {:?} to {:?}", link, original)
+            }
+            Operation::Archive {
+                from,
+                archive,
+                name,
+            } => write!(f, "archive {:?} as {:?} into {:?}", from, name, archive),
+        }
+    }
+}
+
+/// Plan to create the folder of an entry at a nested relative path within a destination,
+/// each folder is planned only once
+#[cfg(any(feature = "copier", feature = "linker"))]
+pub(crate) fn plan_nested_folder(
+    operations: &mut Vec<Operation>,
+    destination: &Path,
+    relative: impl AsRef<Path>,
+) {
+    if let Some(parent) = relative.as_ref().parent() {
+        let folder = Operation::CreateFolder(destination.join(parent));
+        if !parent.as_os_str().is_empty() && !operations.contains(&folder) {
+            operations.push(folder);
+        }
+    }
+}
+
+/// The operations an action would perform in order, returned by the dry-run
+/// versions of the actions without touching the file system
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct Plan {
+    operations: Vec<Operation>,
+}
+
+impl Plan {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn operations(&self) -> &[Operation] {
+        &self.operations
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, Operation> {
+        self.operations.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.operations.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.operations.is_empty()
+    }
+}
+
+impl From<Vec<Operation>> for Plan {
+    fn from(operations: Vec<Operation>) -> Self {
+        Self { operations }
+    }
+}
+
+impl IntoIterator for Plan {
+    type Item = Operation;
+    type IntoIter = std::vec::IntoIter<Operation>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.operations.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Plan {
+    type Item = &'a Operation;
+    type IntoIter = std::slice::Iter<'a, Operation>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.operations.iter()
+    }
+}
+
+/// Prints one operation per line
+impl fmt::Display for Plan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for operation in &self.operations {
+            writeln!(f, "{}", operation)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,138 @@
+use crate::actions::copy_options::where_possible;
+use crate::actions::{Operation, Plan};
+use crate::file_system::copy_folder_with;
+use crate::{FileSystem, Result};
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+
+const CHUNK_SIZE: usize = 64 * 1024;
+
+/// Describes the progress of a copy or move, reported after each copied chunk of a file
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct CopyProgress {
+    copied_bytes: u64,
+    total_bytes: u64,
+    current_file: PathBuf,
+}
+
+impl CopyProgress {
+    /// The amount of bytes copied so far by the whole operation
+    pub fn copied_bytes(&self) -> u64 {
+        self.copied_bytes
+    }
+
+    /// The total size of the copied files, computed before the copy starts
+    pub fn total_bytes(&self) -> u64 {
+        self.total_bytes
+    }
+
+    /// The source file that is being copied
+    pub fn current_file(&self) -> &Path {
+        self.current_file.as_path()
+    }
+}
+
+/// Copies files and folders in chunks notifying a progress callback
+pub(crate) struct ProgressReporter<'a> {
+    progress: CopyProgress,
+    callback: &'a mut dyn FnMut(&CopyProgress),
+}
+
+impl<'a> ProgressReporter<'a> {
+    pub(crate) fn new(total_bytes: u64, callback: &'a mut dyn FnMut(&CopyProgress)) -> Self {
+        Self {
+            progress: CopyProgress {
+                copied_bytes: 0,
+                total_bytes,
+                current_file: PathBuf::new(),
+            },
+            callback,
+        }
+    }
+
+    /// Copy a file overwriting the destination, returns the amount of copied bytes.
+    /// The copy keeps the permissions of the file like a native copy does
+    pub(crate) fn copy_file(
+        &mut self,
+        from_file_system: &dyn FileSystem,
+        from: &Path,
+        to_file_system: &dyn FileSystem,
+        to: &Path,
+    ) -> std::io::Result<u64> {
+        let mut reader = from_file_system.open(from)?;
+        let mut writer = to_file_system.create(to)?;
+
+        self.progress.current_file = from.to_path_buf();
+        (self.callback)(&self.progress);
+
+        let mut buffer = vec![0; CHUNK_SIZE];
+        let mut copied = 0;
+        loop {
+            let read = match reader.read(&mut buffer) {
+                Ok(0) => break,
+                Ok(read) => read,
+                Err(error) if error.kind() == std::io::ErrorKind::Interrupted => continue,
+                Err(error) => return Err(error),
+            };
+            writer.write_all(&buffer[..read])?;
+            copied += read as u64;
+            self.progress.copied_bytes += read as u64;
+            (self.callback)(&self.progress);
+        }
+        writer.flush()?;
+        drop(writer);
+
+        if let Some(mode) = from_file_system.metadata(from)?.mode() {
+            where_possible(to_file_system.set_mode(to, mode))?;
+        }
+        Ok(copied)
+    }
+
+    /// Recursively copy a folder with the same semantics as [`FileSystem::copy_folder`]
+    pub(crate) fn copy_folder(
+        &mut self,
+        from_file_system: &dyn FileSystem,
+        from: &Path,
+        to_file_system: &dyn FileSystem,
+        to: &Path,
+    ) -> Result<()> {
+        copy_folder_with(
+            from_file_system,
+            from,
+            to_file_system,
+            to,
+            &mut |from, to| self.copy_file(from_file_system, from, to_file_system, to),
+        )
+    }
+}
+
+/// Return the total size of the files within an entry, symbolic links are not followed
+pub(crate) fn total_size(file_system: &dyn FileSystem, entry: &Path) -> Result<u64> {
+    let metadata = file_system.metadata(entry)?;
+    if metadata.is_file() {
+        Ok(metadata.len())
+    } else if metadata.is_folder() {
+        let mut size = 0;
+        for each_entry in file_system.read_dir(entry)? {
+            size += total_size(file_system, &each_entry?)?;
+        }
+        Ok(size)
+    } else {
+        Ok(0)
+    }
+}
+
+/// Return the total size of the files copied or moved by a plan
+pub(crate) fn planned_size(file_system: &dyn FileSystem, plan: &Plan) -> Result<u64> {
+    let mut size = 0;
+    for operation in plan {
+        match operation {
+            Operation::CopyFile { from, .. }
+            | Operation::CopyFolder { from, .. }
+            | Operation::MoveFile { from, .. }
+            | Operation::MoveFolder { from, .. } => size += total_size(file_system, from)?,
+            _ => {}
+        }
+    }
+    Ok(size)
+}
@@ -0,0 +1,114 @@
+#[cfg(not(feature = "remover"))]
+compile_error!("Please select a remover feature to build with remover support");
+
+use crate::actions::{Operation, Plan};
+use crate::utils::{is_readable_file, is_readable_folder, normalize_path};
+use crate::{FileMatcherError, FileSystem, ManyEntries, OneEntry, Result};
+use std::path::{Path, PathBuf};
+
+pub trait OneEntryRemover {
+    /// Remove the entry, a folder is removed with all its content.
+    /// Refuses to remove the folder the entry is searched within or any of its parents.
+    /// Returns the path of the removed entry
+    fn remove(&self) -> Result<PathBuf>;
+
+    /// Find and check the entry without removing it,
+    /// returns the operations the removal would perform
+    fn remove_dry_run(&self) -> Result<Plan>;
+}
+
+pub trait ManyEntriesRemover {
+    /// Remove all found entries, folders are removed with all their content.
+    /// Nothing is removed if any of the entries is the folder the entries are searched within
+    /// or any of its parents. Returns the paths of the removed entries
+    fn remove(&self) -> Result<Vec<PathBuf>>;
+
+    /// Find and check the entries without removing them,
+    /// returns the operations the removal would perform
+    fn remove_dry_run(&self) -> Result<Plan>;
+}
+
+impl OneEntryRemover for OneEntry {
+    fn remove(&self) -> Result<PathBuf> {
+        let plan = self.remove_dry_run()?;
+        let removed = remove_planned(self.file_system(), &plan)?;
+        Ok(removed.into_iter().next().unwrap_or_default())
+    }
+
+    fn remove_dry_run(&self) -> Result<Plan> {
+        let entry = self.as_path_buf()?;
+        check_not_search_folder(self.file_system(), self.directory(), &entry)?;
+        Ok(Plan::from(vec![plan_entry(self.file_system(), entry)?]))
+    }
+}
+
+impl ManyEntriesRemover for ManyEntries {
+    fn remove(&self) -> Result<Vec<PathBuf>> {
+        let plan = self.remove_dry_run()?;
+        remove_planned(self.file_system(), &plan)
+    }
+
+    fn remove_dry_run(&self) -> Result<Plan> {
+        let entries = self.find()?;
+        for entry in &entries {
+            check_not_search_folder(self.file_system(), self.directory(), entry)?;
+        }
+
+        // entries within a removed folder are removed together with it
+        let folders = entries
+            .iter()
+            .filter(|each| is_readable_folder(self.file_system(), each))
+            .map(normalize_path)
+            .collect::<Vec<PathBuf>>();
+
+        entries
+            .into_iter()
+            .filter(|each| {
+                let each = normalize_path(each);
+                !folders
+                    .iter()
+                    .any(|folder| each != *folder && each.starts_with(folder))
+            })
+            .map(|each| plan_entry(self.file_system(), each))
+            .collect::<Result<Vec<Operation>>>()
+            .map(Plan::from)
+    }
+}
+
+/// Compare absolute paths, a relative search folder such as `.` would otherwise
+/// never start with an absolute path of the same folder
+fn check_not_search_folder(
+    file_system: &dyn FileSystem,
+    directory: &Path,
+    entry: &Path,
+) -> Result<()> {
+    let directory = normalize_path(file_system.absolute_path(directory));
+    if directory.starts_with(normalize_path(file_system.absolute_path(entry))) {
+        FileMatcherError::RemovingSearchFolder(entry.to_path_buf()).into()
+    } else {
+        Ok(())
+    }
+}
+
+fn plan_entry(file_system: &dyn FileSystem, entry: PathBuf) -> Result<Operation> {
+    if is_readable_file(file_system, &entry) {
+        Ok(Operation::RemoveFile(entry))
+    } else if is_readable_folder(file_system, &entry) {
+        Ok(Operation::RemoveFolder(entry))
+    } else {
+        FileMatcherError::NotReadable(entry).into()
+    }
+}
+
+fn remove_planned(file_system: &dyn FileSystem, plan: &Plan) -> Result<Vec<PathBuf>> {
+    let mut removed = vec![];
+    for operation in plan {
+        match operation {
+            Operation::RemoveFile(path) => file_system.remove_file(path)?,
+            Operation::RemoveFolder(path) => file_system.remove_dir_all(path)?,
+            operation => unreachable!("Remover can not perform {}", operation),
+        }
+        removed.push(operation.destination().to_path_buf());
+    }
+    Ok(removed)
+}
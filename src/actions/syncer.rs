@@ -0,0 +1,390 @@
+#[cfg(not(feature = "syncer"))]
+compile_error!("Please select a syncer feature to build with syncer support");
+
+use crate::actions::copier::execute_copy;
+use crate::actions::plan::plan_nested_folder;
+use crate::actions::{Operation, Plan};
+use crate::utils::{entry_file_name, entry_relative_path, is_readable_folder};
+#[cfg(any(feature = "sha256", feature = "blake3"))]
+use crate::Checksum;
+use crate::{EntryMetadata, FileMatcherError, FileSystem, ManyEntries, OneEntry, Result};
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+/// How a synchronized file is compared with its existing copy to decide if it changed
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub enum SyncComparison {
+    /// The size or the modification time differ. The modification time is preserved
+    /// by the synchronization so that unchanged files are not copied again
+    #[default]
+    SizeAndModified,
+    /// The checksums of the contents differ
+    #[cfg(any(feature = "sha256", feature = "blake3"))]
+    Checksum(Checksum),
+}
+
+/// Describes how the entries are synchronized. By default the files are compared
+/// by size and modification time and extraneous entries are kept
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub struct SyncOptions {
+    comparison: SyncComparison,
+    delete: bool,
+}
+
+impl SyncOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Compare the files with their copies by checksums instead of size and modification time
+    #[cfg(any(feature = "sha256", feature = "blake3"))]
+    pub fn compare_checksums(mut self, checksum: Checksum) -> Self {
+        self.comparison = SyncComparison::Checksum(checksum);
+        self
+    }
+
+    /// Remove the entries of the destination that have no synchronized counterpart
+    pub fn delete_extraneous(mut self) -> Self {
+        self.delete = true;
+        self
+    }
+
+    pub fn comparison(&self) -> SyncComparison {
+        self.comparison
+    }
+
+    pub fn deletes_extraneous(&self) -> bool {
+        self.delete
+    }
+}
+
+/// The paths within the destination changed by a synchronization
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct SyncSummary {
+    added: Vec<PathBuf>,
+    updated: Vec<PathBuf>,
+    removed: Vec<PathBuf>,
+}
+
+impl SyncSummary {
+    /// New files and folders
+    pub fn added(&self) -> &[PathBuf] {
+        self.added.as_slice()
+    }
+
+    /// Files that changed, or entries replaced by an entry of another type
+    pub fn updated(&self) -> &[PathBuf] {
+        self.updated.as_slice()
+    }
+
+    /// Extraneous files and folders
+    pub fn removed(&self) -> &[PathBuf] {
+        self.removed.as_slice()
+    }
+
+    /// Return true if the destination was already in sync
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.updated.is_empty() && self.removed.is_empty()
+    }
+}
+
+pub trait OneEntrySyncer {
+    /// Synchronize the entry with its copy inside of a destination folder,
+    /// or at the destination path if it is a file and the destination is not an existing folder.
+    /// Only new and changed files are copied, folders are synchronized recursively
+    fn sync(&self, destination: impl AsRef<Path>) -> Result<SyncSummary> {
+        self.sync_with_options(destination, SyncOptions::new())
+    }
+
+    fn sync_with_options(
+        &self,
+        destination: impl AsRef<Path>,
+        options: SyncOptions,
+    ) -> Result<SyncSummary>;
+
+    /// Compare the entry with its copy without changing it,
+    /// returns the operations the synchronization would perform
+    fn sync_dry_run(&self, destination: impl AsRef<Path>, options: SyncOptions) -> Result<Plan>;
+}
+
+pub trait ManyEntriesSyncer {
+    /// Synchronize all found entries with their copies inside of a destination folder
+    /// creating it if necessary. Entries found within nested folders of a recursive search
+    /// keep their path relative to the searched folder. When extraneous entries are deleted,
+    /// the entries of the destination folder that were not found are removed too
+    fn sync(&self, destination: impl AsRef<Path>) -> Result<SyncSummary> {
+        self.sync_with_options(destination, SyncOptions::new())
+    }
+
+    fn sync_with_options(
+        &self,
+        destination: impl AsRef<Path>,
+        options: SyncOptions,
+    ) -> Result<SyncSummary>;
+
+    /// Compare the entries with their copies without changing them,
+    /// returns the operations the synchronization would perform
+    fn sync_dry_run(&self, destination: impl AsRef<Path>, options: SyncOptions) -> Result<Plan>;
+}
+
+impl OneEntrySyncer for OneEntry {
+    fn sync_with_options(
+        &self,
+        destination: impl AsRef<Path>,
+        options: SyncOptions,
+    ) -> Result<SyncSummary> {
+        let syncer = plan_one_entry(self, destination.as_ref(), options)?;
+        syncer.execute()
+    }
+
+    fn sync_dry_run(&self, destination: impl AsRef<Path>, options: SyncOptions) -> Result<Plan> {
+        Ok(Plan::from(
+            plan_one_entry(self, destination.as_ref(), options)?.operations,
+        ))
+    }
+}
+
+impl ManyEntriesSyncer for ManyEntries {
+    fn sync_with_options(
+        &self,
+        destination: impl AsRef<Path>,
+        options: SyncOptions,
+    ) -> Result<SyncSummary> {
+        let syncer = plan_many_entries(self, destination.as_ref(), options)?;
+        syncer.execute()
+    }
+
+    fn sync_dry_run(&self, destination: impl AsRef<Path>, options: SyncOptions) -> Result<Plan> {
+        Ok(Plan::from(
+            plan_many_entries(self, destination.as_ref(), options)?.operations,
+        ))
+    }
+}
+
+fn plan_one_entry<'a>(
+    entry: &'a OneEntry,
+    destination: &Path,
+    options: SyncOptions,
+) -> Result<Syncer<'a>> {
+    let file_system = entry.file_system();
+    let path = entry.as_path_buf()?;
+    let name = match entry.entry().name_alias() {
+        Some(alias) => alias,
+        None => entry_file_name(&path)?,
+    };
+
+    let to =
+        if file_system.metadata(&path)?.is_file() && !file_system.resolves_to_folder(destination) {
+            destination.to_path_buf()
+        } else {
+            destination.join(name)
+        };
+
+    let mut syncer = Syncer::new(file_system, options);
+    syncer.plan_entry(&path, &to)?;
+    Ok(syncer)
+}
+
+fn plan_many_entries<'a>(
+    entries: &'a ManyEntries,
+    destination: &Path,
+    options: SyncOptions,
+) -> Result<Syncer<'a>> {
+    let file_system = entries.file_system();
+    let paths = entries.find()?;
+
+    let mut syncer = Syncer::new(file_system, options);
+    if !file_system.resolves_to_folder(destination) {
+        syncer
+            .operations
+            .push(Operation::CreateFolder(destination.to_path_buf()));
+    }
+
+    // the destination folder keeps the entries named after the found ones
+    // or after the nested folders they were found within
+    let mut names = vec![];
+    for path in &paths {
+        let relative = Path::new(entry_relative_path(entries.directory(), path)?);
+        let to = destination.join(relative);
+        if to
+            .parent()
+            .is_some_and(|folder| !file_system.resolves_to_folder(folder))
+        {
+            plan_nested_folder(&mut syncer.operations, destination, relative);
+        }
+        syncer.plan_entry(path, &to)?;
+        if let Some(name) = relative.iter().next().and_then(|name| name.to_str()) {
+            names.push(name.to_string());
+        }
+    }
+    if options.delete && file_system.resolves_to_folder(destination) {
+        syncer.plan_extraneous(destination, |name| {
+            names.iter().any(|each| each.as_str() == name)
+        })?;
+    }
+    Ok(syncer)
+}
+
+/// Compares entries with their copies within a file system and collects
+/// the operations bringing the copies in sync together with their summary
+struct Syncer<'a> {
+    file_system: &'a dyn FileSystem,
+    options: SyncOptions,
+    operations: Vec<Operation>,
+    summary: SyncSummary,
+}
+
+impl<'a> Syncer<'a> {
+    fn new(file_system: &'a dyn FileSystem, options: SyncOptions) -> Self {
+        Self {
+            file_system,
+            options,
+            operations: vec![],
+            summary: SyncSummary::default(),
+        }
+    }
+
+    fn plan_entry(&mut self, from: &Path, to: &Path) -> Result<()> {
+        let source = self.file_system.metadata(from)?;
+        let copy = self.file_system.metadata(to).ok();
+
+        if source.is_file() {
+            match copy {
+                None => {
+                    self.copy_file(from, to);
+                    self.summary.added.push(to.to_path_buf());
+                }
+                Some(copy) if copy.is_folder() => {
+                    self.operations
+                        .push(Operation::RemoveFolder(to.to_path_buf()));
+                    self.copy_file(from, to);
+                    self.summary.updated.push(to.to_path_buf());
+                }
+                Some(copy) => {
+                    if self.is_changed(from, &source, to, &copy)? {
+                        self.copy_file(from, to);
+                        self.summary.updated.push(to.to_path_buf());
+                    }
+                }
+            }
+        } else if source.is_folder() {
+            match copy {
+                None => {
+                    self.create_folder(to);
+                    self.summary.added.push(to.to_path_buf());
+                }
+                Some(copy) if !copy.is_folder() => {
+                    self.operations
+                        .push(Operation::RemoveFile(to.to_path_buf()));
+                    self.create_folder(to);
+                    self.summary.updated.push(to.to_path_buf());
+                }
+                Some(_) => {}
+            }
+
+            let mut names = vec![];
+            for each_entry in sorted_entries(self.file_system, from)? {
+                let name = entry_file_name(&each_entry)?;
+                let each_metadata = self.file_system.metadata(&each_entry)?;
+                // only files and folders are synchronized, symbolic links are skipped
+                if each_metadata.is_file() || each_metadata.is_folder() {
+                    self.plan_entry(&each_entry, &to.join(name))?;
+                    names.push(name.to_string());
+                }
+            }
+            if self.options.delete && is_readable_folder(self.file_system, to) {
+                self.plan_extraneous(to, |name| names.iter().any(|each| each.as_str() == name))?;
+            }
+        } else {
+            return FileMatcherError::NotReadable(from.to_path_buf()).into();
+        }
+        Ok(())
+    }
+
+    /// Remove the entries of a folder whose names are not synchronized
+    fn plan_extraneous(&mut self, folder: &Path, is_synced: impl Fn(&str) -> bool) -> Result<()> {
+        for each_entry in sorted_entries(self.file_system, folder)? {
+            if is_synced(entry_file_name(&each_entry)?) {
+                continue;
+            }
+            if is_readable_folder(self.file_system, &each_entry) {
+                self.operations
+                    .push(Operation::RemoveFolder(each_entry.clone()));
+            } else {
+                self.operations
+                    .push(Operation::RemoveFile(each_entry.clone()));
+            }
+            self.summary.removed.push(each_entry);
+        }
+        Ok(())
+    }
+
+    // the paths are only read to compare the checksums
+    #[cfg_attr(
+        not(any(feature = "sha256", feature = "blake3")),
+        allow(unused_variables)
+    )]
+    fn is_changed(
+        &self,
+        from: &Path,
+        source: &EntryMetadata,
+        to: &Path,
+        copy: &EntryMetadata,
+    ) -> Result<bool> {
+        match self.options.comparison {
+            SyncComparison::SizeAndModified => {
+                Ok(source.len() != copy.len() || source.modified() != copy.modified())
+            }
+            #[cfg(any(feature = "sha256", feature = "blake3"))]
+            SyncComparison::Checksum(checksum) => Ok(source.len() != copy.len()
+                || checksum.of_file(self.file_system, from)?
+                    != checksum.of_file(self.file_system, to)?),
+        }
+    }
+
+    fn copy_file(&mut self, from: &Path, to: &Path) {
+        self.operations.push(Operation::CopyFile {
+            from: from.to_path_buf(),
+            to: to.to_path_buf(),
+        });
+    }
+
+    fn create_folder(&mut self, path: &Path) {
+        self.operations
+            .push(Operation::CreateFolder(path.to_path_buf()));
+    }
+
+    fn execute(self) -> Result<SyncSummary> {
+        for operation in &self.operations {
+            match operation {
+                Operation::RemoveFile(path) => self.file_system.remove_file(path)?,
+                Operation::RemoveFolder(path) => self.file_system.remove_dir_all(path)?,
+                Operation::CopyFile { from, to } => {
+                    execute_copy(self.file_system, operation)?;
+                    preserve_modified(self.file_system, from, to)?;
+                }
+                operation => execute_copy(self.file_system, operation)?,
+            }
+        }
+        Ok(self.summary)
+    }
+}
+
+/// Set the modification time of a copy to the one of the file, if the file system allows it
+fn preserve_modified(file_system: &dyn FileSystem, from: &Path, to: &Path) -> Result<()> {
+    if let Some(modified) = file_system.metadata(from)?.modified() {
+        match file_system.set_times(to, None, Some(modified)) {
+            Err(error) if error.kind() == ErrorKind::Unsupported => {}
+            result => result?,
+        }
+    }
+    Ok(())
+}
+
+fn sorted_entries(file_system: &dyn FileSystem, folder: &Path) -> Result<Vec<PathBuf>> {
+    let mut entries = file_system
+        .read_dir(folder)?
+        .collect::<std::io::Result<Vec<PathBuf>>>()?;
+    entries.sort();
+    Ok(entries)
+}
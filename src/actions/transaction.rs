@@ -0,0 +1,368 @@
+#[cfg(not(feature = "transaction"))]
+compile_error!("Please select a transaction feature to build with transaction support");
+
+use crate::actions::copier::execute_copy;
+use crate::actions::copy_options::copy_destination;
+use crate::actions::mover::execute_move;
+use crate::actions::plan::plan_nested_folder;
+use crate::actions::{
+    ManyEntriesCopier, ManyEntriesRemover, OneEntryCopier, OneEntryMover, OneEntryRemover,
+    Operation, Plan,
+};
+use crate::utils::{entry_relative_path, is_readable_file, is_readable_folder, temporary_sibling};
+use crate::{FileMatcherError, FileSystem, ManyEntries, OneEntry, Result};
+use std::io::{Error, ErrorKind};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// Groups copies, moves and removals of found entries so that they can be undone together.
+/// Every performed operation is recorded, removed and overwritten entries are kept
+/// as hidden backups next to them until the transaction is committed.
+/// A transaction dropped without a commit is rolled back
+/// ```no_run
+/// use file_matcher::{FileNamed, FilesNamed, Transaction};
+///
+/// # use file_matcher::Result;
+/// # fn main() -> Result<()> {
+///     Transaction::run(|transaction| {
+///         transaction.move_many(&FilesNamed::wildmatch("*.dll").within("build"), "install")?;
+///         transaction.remove(&FileNamed::exact("install.lock").within("install"))
+///     })?;
+/// #    Ok(())
+/// # }
+/// ```
+#[derive(Default)]
+pub struct Transaction {
+    completed: Vec<Operation>,
+    undo: Vec<Undo>,
+    backups: Vec<(Arc<dyn FileSystem>, PathBuf)>,
+}
+
+/// Reverts a single change of a file system
+struct Undo {
+    file_system: Arc<dyn FileSystem>,
+    change: Change,
+}
+
+enum Change {
+    /// An entry was created and should be removed
+    Created(PathBuf),
+    /// An entry was moved and should be moved back, merging it into what is left of the entry
+    /// when the move failed to remove it completely
+    Moved { from: PathBuf, to: PathBuf },
+    /// An entry was moved out of the way to a backup and should be restored
+    BackedUp { path: PathBuf, backup: PathBuf },
+}
+
+impl Transaction {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Perform the steps within a new transaction, commit it if all of them succeed
+    /// or roll it back and return the error of the failed step
+    pub fn run<T>(steps: impl FnOnce(&mut Transaction) -> Result<T>) -> Result<T> {
+        let mut transaction = Self::new();
+        match steps(&mut transaction) {
+            Ok(result) => {
+                transaction.commit()?;
+                Ok(result)
+            }
+            Err(error) => {
+                // the error of the step is more relevant than the one of the rollback
+                let _ = transaction.rollback();
+                Err(error)
+            }
+        }
+    }
+
+    /// Copy the entry as [`OneEntryCopier::copy`] does, returns the path of the copy
+    pub fn copy(&mut self, entry: &OneEntry, destination: impl AsRef<Path>) -> Result<PathBuf> {
+        let plan = entry.copy_dry_run(destination.as_ref())?;
+        self.perform(&entry.shared_file_system(), &plan)?;
+        Ok(last_destination(&plan, destination.as_ref()))
+    }
+
+    /// Copy the entries as [`ManyEntriesCopier::copy`] does, returns the paths of the copies
+    pub fn copy_many(
+        &mut self,
+        entries: &ManyEntries,
+        destination: impl AsRef<Path>,
+    ) -> Result<Vec<PathBuf>> {
+        let plan = entries.copy_dry_run(destination)?;
+        self.perform(&entries.shared_file_system(), &plan)?;
+        Ok(copied_or_moved(&plan))
+    }
+
+    /// Move the entry as [`OneEntryMover::move`] does, returns its new path
+    pub fn r#move(&mut self, entry: &OneEntry, destination: impl AsRef<Path>) -> Result<PathBuf> {
+        let plan = entry.move_dry_run(destination.as_ref())?;
+        self.perform(&entry.shared_file_system(), &plan)?;
+        Ok(last_destination(&plan, destination.as_ref()))
+    }
+
+    /// Move the entries inside of a destination folder creating it if necessary,
+    /// returns their new paths. Entries found within nested folders of a recursive search
+    /// keep their path relative to the searched folder
+    pub fn move_many(
+        &mut self,
+        entries: &ManyEntries,
+        destination: impl AsRef<Path>,
+    ) -> Result<Vec<PathBuf>> {
+        let destination = destination.as_ref();
+        let file_system = entries.shared_file_system();
+
+        let mut operations = vec![Operation::CreateFolder(destination.to_path_buf())];
+        for entry in entries.find()? {
+            let relative = entry_relative_path(entries.directory(), &entry)?;
+            plan_nested_folder(&mut operations, destination, relative);
+            let to = destination.join(relative);
+            operations.push(plan_move(file_system.as_ref(), entry, to)?);
+        }
+        let plan = Plan::from(operations);
+
+        self.perform(&file_system, &plan)?;
+        Ok(copied_or_moved(&plan))
+    }
+
+    /// Remove the entry as [`OneEntryRemover::remove`] does keeping a backup of it,
+    /// returns the path of the removed entry
+    pub fn remove(&mut self, entry: &OneEntry) -> Result<PathBuf> {
+        let plan = entry.remove_dry_run()?;
+        self.perform(&entry.shared_file_system(), &plan)?;
+        Ok(last_destination(&plan, Path::new("")))
+    }
+
+    /// Remove the entries as [`ManyEntriesRemover::remove`] does keeping backups of them,
+    /// returns the paths of the removed entries
+    pub fn remove_many(&mut self, entries: &ManyEntries) -> Result<Vec<PathBuf>> {
+        let plan = entries.remove_dry_run()?;
+        self.perform(&entries.shared_file_system(), &plan)?;
+        Ok(plan
+            .iter()
+            .map(|operation| operation.destination().to_path_buf())
+            .collect())
+    }
+
+    /// Return the operations completed so far
+    pub fn completed(&self) -> Plan {
+        Plan::from(self.completed.clone())
+    }
+
+    /// Keep all changes and delete the backups, returns the completed operations
+    pub fn commit(mut self) -> Result<Plan> {
+        self.undo.clear();
+        for (file_system, backup) in std::mem::take(&mut self.backups) {
+            remove_entry(file_system.as_ref(), &backup)?;
+        }
+        Ok(Plan::from(std::mem::take(&mut self.completed)))
+    }
+
+    /// Undo all changes in the reverse order, including the partial changes of a failed step.
+    /// The rollback continues after a failure and returns the first error
+    pub fn rollback(mut self) -> Result<()> {
+        self.undo_all()
+    }
+
+    fn undo_all(&mut self) -> Result<()> {
+        self.backups.clear();
+        self.completed.clear();
+
+        let mut result = Ok(());
+        while let Some(undo) = self.undo.pop() {
+            let file_system = undo.file_system.as_ref();
+            let undone = match &undo.change {
+                Change::Created(path) => remove_entry(file_system, path),
+                Change::Moved { from, to } => move_back(file_system, to, from),
+                Change::BackedUp { path, backup } => relocate(file_system, backup, path),
+            };
+            match undone {
+                Err(FileMatcherError::IoError(error)) if error.kind() == ErrorKind::NotFound => {}
+                Err(error) if result.is_ok() => result = Err(error),
+                _ => {}
+            }
+        }
+        result
+    }
+
+    /// Perform the operations one by one, recording how to undo each of them before it starts
+    fn perform(&mut self, file_system: &Arc<dyn FileSystem>, plan: &Plan) -> Result<()> {
+        for operation in plan {
+            self.prepare(file_system, operation)?;
+            match operation {
+                Operation::CreateFolder(_)
+                | Operation::CopyFile { .. }
+                | Operation::CopyFolder { .. } => execute_copy(file_system.as_ref(), operation)?,
+                Operation::MoveFile { .. } | Operation::MoveFolder { .. } => {
+                    execute_move(file_system.as_ref(), operation)?
+                }
+                // the backup made in preparation already removed the entry
+                Operation::RemoveFile(_) | Operation::RemoveFolder(_) => {}
+                operation => unreachable!("Transaction can not perform {}", operation),
+            }
+            self.completed.push(operation.clone());
+        }
+        Ok(())
+    }
+
+    fn prepare(&mut self, file_system: &Arc<dyn FileSystem>, operation: &Operation) -> Result<()> {
+        let fs = file_system.as_ref();
+        match operation {
+            Operation::CreateFolder(path) => self.record_created_folders(file_system, path),
+            Operation::CopyFile { to, .. } | Operation::MoveFile { to, .. } => {
+                if fs.metadata(to).is_ok() {
+                    self.back_up(file_system, to)?;
+                }
+                self.record_copy(file_system, operation, to.clone());
+            }
+            Operation::CopyFolder { .. } | Operation::MoveFolder { .. } => {
+                if let Some(copy) = copy_destination(fs, operation) {
+                    // merging into an existing folder could not be undone
+                    if fs.metadata(&copy).is_ok() {
+                        return Err(Error::new(
+                            ErrorKind::AlreadyExists,
+                            format!("{:?} already exists", copy),
+                        )
+                        .into());
+                    }
+                    // the missing parents of a folder are created by the copy
+                    if let Some(parent) = copy.parent() {
+                        self.record_created_folders(file_system, parent);
+                    }
+                    self.record_copy(file_system, operation, copy);
+                }
+            }
+            Operation::RemoveFile(path) | Operation::RemoveFolder(path) => {
+                self.back_up(file_system, path)?;
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Record the outermost missing folder of a path to be created
+    fn record_created_folders(&mut self, file_system: &Arc<dyn FileSystem>, path: &Path) {
+        if let Some(created) = path
+            .ancestors()
+            .take_while(|each| !each.as_os_str().is_empty() && file_system.metadata(each).is_err())
+            .last()
+        {
+            self.record(file_system, Change::Created(created.to_path_buf()));
+        }
+    }
+
+    /// Record how to undo a copy or a move creating a given entry
+    fn record_copy(
+        &mut self,
+        file_system: &Arc<dyn FileSystem>,
+        operation: &Operation,
+        copy: PathBuf,
+    ) {
+        match operation {
+            Operation::MoveFile { from, .. } | Operation::MoveFolder { from, .. } => self.record(
+                file_system,
+                Change::Moved {
+                    from: from.clone(),
+                    to: copy,
+                },
+            ),
+            _ => self.record(file_system, Change::Created(copy)),
+        }
+    }
+
+    /// Move an entry out of the way to a hidden sibling to be restored on rollback
+    fn back_up(&mut self, file_system: &Arc<dyn FileSystem>, path: &Path) -> Result<()> {
+        let backup = temporary_sibling(path);
+        relocate(file_system.as_ref(), path, &backup)?;
+        self.backups.push((file_system.clone(), backup.clone()));
+        self.record(
+            file_system,
+            Change::BackedUp {
+                path: path.to_path_buf(),
+                backup,
+            },
+        );
+        Ok(())
+    }
+
+    fn record(&mut self, file_system: &Arc<dyn FileSystem>, change: Change) {
+        self.undo.push(Undo {
+            file_system: file_system.clone(),
+            change,
+        });
+    }
+}
+
+impl Drop for Transaction {
+    fn drop(&mut self) {
+        let _ = self.undo_all();
+    }
+}
+
+fn plan_move(file_system: &dyn FileSystem, from: PathBuf, to: PathBuf) -> Result<Operation> {
+    if is_readable_file(file_system, &from) {
+        Ok(Operation::MoveFile { from, to })
+    } else if is_readable_folder(file_system, &from) {
+        Ok(Operation::MoveFolder { from, to })
+    } else {
+        FileMatcherError::NotReadable(from).into()
+    }
+}
+
+fn last_destination(plan: &Plan, default: &Path) -> PathBuf {
+    plan.iter()
+        .last()
+        .map(|operation| operation.destination())
+        .unwrap_or(default)
+        .to_path_buf()
+}
+
+fn copied_or_moved(plan: &Plan) -> Vec<PathBuf> {
+    plan.iter()
+        .filter(|operation| !matches!(operation, Operation::CreateFolder(_)))
+        .map(|operation| operation.destination().to_path_buf())
+        .collect()
+}
+
+/// Rename an entry, copying and removing it when the file system can not rename
+fn relocate(file_system: &dyn FileSystem, from: &Path, to: &Path) -> Result<()> {
+    match file_system.rename(from, to) {
+        Err(error) if error.kind() == ErrorKind::Unsupported => {
+            if file_system.metadata(from)?.is_folder() {
+                file_system.copy_folder(from, to)?;
+            } else {
+                file_system.copy_file(from, to)?;
+            }
+            remove_entry(file_system, from)
+        }
+        result => Ok(result?),
+    }
+}
+
+/// Move a moved entry back to where it was. The removal of a moved folder may fail partway,
+/// so only the entries missing from the origin are moved back, the remaining ones were
+/// not touched by the move and the copies of them are removed
+fn move_back(file_system: &dyn FileSystem, moved: &Path, origin: &Path) -> Result<()> {
+    let origin_metadata = match file_system.metadata(origin) {
+        Ok(metadata) => metadata,
+        Err(_) => return relocate(file_system, moved, origin),
+    };
+    if origin_metadata.is_folder() && file_system.metadata(moved)?.is_folder() {
+        for entry in file_system.read_dir(moved)? {
+            let entry = entry?;
+            if let Some(file_name) = entry.file_name() {
+                move_back(file_system, &entry, &origin.join(file_name))?;
+            }
+        }
+    }
+    remove_entry(file_system, moved)
+}
+
+fn remove_entry(file_system: &dyn FileSystem, path: &Path) -> Result<()> {
+    if file_system.metadata(path)?.is_folder() {
+        file_system.remove_dir_all(path)?;
+    } else {
+        file_system.remove_file(path)?;
+    }
+    Ok(())
+}
@@ -1,3 +1,5 @@
+#[cfg(any(feature = "sha256", feature = "blake3"))]
+use crate::ContentHash;
 use crate::{EntryName, EntryType, OneEntry, OneEntryNamed};
 use std::path::PathBuf;
 
@@ -49,4 +51,9 @@ impl OneEntryNamed for EntityAlias {
     fn boxed(&self) -> Box<dyn OneEntryNamed> {
         Box::new(self.clone())
     }
+
+    #[cfg(any(feature = "sha256", feature = "blake3"))]
+    fn content_hash(&self) -> Option<&ContentHash> {
+        self.entity_named.content_hash()
+    }
 }
@@ -0,0 +1,285 @@
+#[cfg(not(feature = "cli"))]
+compile_error!("Please select a cli feature to build the command-line tool");
+
+extern crate clap;
+extern crate file_matcher;
+
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use file_matcher::{
+    EntityAlias, EntryName, EntryType, FileMatcherError, ManyEntries, ManyEntriesNamed, OneEntry,
+    OneEntryCopier, OneEntryMover, OneEntryNamed,
+};
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+/// Exit code when no matching entry was found
+const NOT_FOUND: u8 = 3;
+/// Exit code when more matching entries were found than expected
+const TOO_MANY: u8 = 4;
+/// Exit code of any other failure, invalid arguments exit with 2
+const FAILURE: u8 = 1;
+
+/// Find, list, copy and move files and folders matching a name
+#[derive(Debug, Parser)]
+#[command(name = "file-matcher", version)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Print the path of exactly one matching entry
+    Find(OneEntryArgs),
+    /// Exit successfully if there exists exactly one matching entry
+    Exists(OneEntryArgs),
+    /// Print the paths of all matching entries
+    List(ManyEntriesArgs),
+    /// Copy exactly one matching entry to a destination, printing the path of the copy
+    Copy(ActionArgs),
+    /// Move exactly one matching entry to a destination, printing its new path
+    Move(ActionArgs),
+}
+
+#[derive(Debug, Args)]
+#[group(required = true, multiple = false)]
+struct NameArgs {
+    /// Match entries with exactly this name
+    #[arg(long, value_name = "NAME")]
+    exact: Option<String>,
+    /// Match entries with any of the comma separated names
+    #[arg(long, value_name = "NAMES", value_delimiter = ',')]
+    any: Option<Vec<String>>,
+    /// Match entries with names matching a regular expression
+    #[arg(long, value_name = "PATTERN")]
+    regex: Option<String>,
+    /// Match entries with names matching a wildcard pattern
+    #[arg(long, value_name = "PATTERN")]
+    wildmatch: Option<String>,
+}
+
+impl NameArgs {
+    fn entry_name(&self) -> EntryName {
+        if let Some(name) = &self.exact {
+            EntryName::Exact(name.clone())
+        } else if let Some(names) = &self.any {
+            EntryName::Any(names.clone())
+        } else if let Some(pattern) = &self.regex {
+            EntryName::Regex(pattern.clone())
+        } else if let Some(pattern) = &self.wildmatch {
+            EntryName::Wildmatch(pattern.clone())
+        } else {
+            unreachable!("clap requires one of the name arguments")
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum TypeArg {
+    File,
+    Folder,
+    Any,
+}
+
+impl From<TypeArg> for EntryType {
+    fn from(entry_type: TypeArg) -> Self {
+        match entry_type {
+            TypeArg::File => EntryType::File,
+            TypeArg::Folder => EntryType::Folder,
+            TypeArg::Any => EntryType::Any,
+        }
+    }
+}
+
+#[derive(Debug, Args)]
+struct EntryArgs {
+    #[command(flatten)]
+    name: NameArgs,
+    /// The type of the matching entries
+    #[arg(long = "type", value_enum, default_value = "file")]
+    entry_type: TypeArg,
+    /// The folder to search within
+    #[arg(long, value_name = "FOLDER", default_value = ".")]
+    within: PathBuf,
+}
+
+impl EntryArgs {
+    fn named(&self) -> EntriesNamed {
+        EntriesNamed {
+            entry_name: self.name.entry_name(),
+            entry_type: self.entry_type.into(),
+        }
+    }
+}
+
+#[derive(Debug, Args)]
+struct OneEntryArgs {
+    #[command(flatten)]
+    entry: EntryArgs,
+}
+
+impl OneEntryArgs {
+    fn one_entry(&self) -> OneEntry {
+        OneEntryNamed::within_path_buf(&self.entry.named(), self.entry.within.clone())
+    }
+}
+
+#[derive(Debug, Args)]
+struct ManyEntriesArgs {
+    #[command(flatten)]
+    entry: EntryArgs,
+    /// Search within nested folders too
+    #[arg(long)]
+    recursive: bool,
+    /// Fail unless at least this amount of entries is found
+    #[arg(long, value_name = "COUNT")]
+    at_least: Option<usize>,
+    /// Fail if more than this amount of entries is found
+    #[arg(long, value_name = "COUNT")]
+    at_most: Option<usize>,
+}
+
+impl ManyEntriesArgs {
+    fn many_entries(&self) -> ManyEntries {
+        let mut entries =
+            ManyEntriesNamed::within_path_buf(&self.entry.named(), self.entry.within.clone())
+                .sorted();
+        if self.recursive {
+            entries = entries.recursive();
+        }
+        if let Some(min) = self.at_least {
+            entries = entries.at_least(min);
+        }
+        if let Some(max) = self.at_most {
+            entries = entries.at_most(max);
+        }
+        entries
+    }
+}
+
+#[derive(Debug, Args)]
+struct ActionArgs {
+    #[command(flatten)]
+    entry: EntryArgs,
+    /// Copy or move the entry under this name
+    #[arg(long, value_name = "NAME")]
+    alias: Option<String>,
+    /// An existing folder to copy or move the entry into, otherwise the new path of the entry
+    destination: PathBuf,
+}
+
+impl ActionArgs {
+    fn one_entry(&self) -> OneEntry {
+        let named = self.entry.named();
+        let within = self.entry.within.clone();
+        match &self.alias {
+            Some(alias) => EntityAlias::new(OneEntryNamed::boxed(&named), alias).within(within),
+            None => OneEntryNamed::within_path_buf(&named, within),
+        }
+    }
+}
+
+/// Entries of a type given on the command line
+#[derive(Debug, Clone)]
+struct EntriesNamed {
+    entry_name: EntryName,
+    entry_type: EntryType,
+}
+
+impl OneEntryNamed for EntriesNamed {
+    fn within_path_buf(&self, directory: PathBuf) -> OneEntry {
+        OneEntry::new(OneEntryNamed::boxed(self), directory)
+    }
+
+    fn entry_name(&self) -> &EntryName {
+        &self.entry_name
+    }
+
+    fn entry_type(&self) -> &EntryType {
+        &self.entry_type
+    }
+
+    fn name_alias(&self) -> Option<&str> {
+        None
+    }
+
+    fn boxed(&self) -> Box<dyn OneEntryNamed> {
+        Box::new(self.clone())
+    }
+}
+
+impl ManyEntriesNamed for EntriesNamed {
+    fn within_path_buf(&self, directory: PathBuf) -> ManyEntries {
+        ManyEntries::new(ManyEntriesNamed::boxed(self), directory)
+    }
+
+    fn entry_name(&self) -> &EntryName {
+        &self.entry_name
+    }
+
+    fn entry_type(&self) -> &EntryType {
+        &self.entry_type
+    }
+
+    fn name_alias(&self) -> Option<&str> {
+        None
+    }
+
+    fn boxed(&self) -> Box<dyn ManyEntriesNamed> {
+        Box::new(self.clone())
+    }
+}
+
+fn run(command: &Command) -> file_matcher::Result<()> {
+    match command {
+        Command::Find(args) => println!("{}", args.one_entry().find()?.display()),
+        Command::Exists(args) => {
+            args.one_entry().find()?;
+        }
+        Command::List(args) => {
+            for entry in args.many_entries().find()? {
+                println!("{}", entry.display());
+            }
+        }
+        Command::Copy(args) => {
+            println!("{}", args.one_entry().copy(&args.destination)?.display())
+        }
+        Command::Move(args) => {
+            println!("{}", args.one_entry().r#move(&args.destination)?.display())
+        }
+    }
+    Ok(())
+}
+
+fn exit_code(error: &FileMatcherError) -> u8 {
+    match error {
+        FileMatcherError::NotExists(_) => NOT_FOUND,
+        FileMatcherError::TooMany(_) => TOO_MANY,
+        FileMatcherError::WrongCount(entries, found) => {
+            if found.len() < entries.count().min() {
+                NOT_FOUND
+            } else {
+                TOO_MANY
+            }
+        }
+        _ => FAILURE,
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    match run(&cli.command) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            // a missing or ambiguous entry is an expected answer of exists
+            let answered = matches!(
+                error,
+                FileMatcherError::NotExists(_) | FileMatcherError::TooMany(_)
+            );
+            if !(answered && matches!(cli.command, Command::Exists(_))) {
+                eprintln!("{}", error);
+            }
+            ExitCode::from(exit_code(&error))
+        }
+    }
+}
@@ -0,0 +1,165 @@
+#[cfg(not(any(feature = "sha256", feature = "blake3")))]
+compile_error!("Please select a sha256 or blake3 feature to build with checksum support");
+
+use crate::FileSystem;
+#[cfg(any(feature = "copier", feature = "mover"))]
+use crate::{FileMatcherError, Result};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::io::Read;
+use std::path::Path;
+
+const CHUNK_SIZE: usize = 64 * 1024;
+
+/// An algorithm to compute a checksum of a file content
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Checksum {
+    #[cfg(feature = "sha256")]
+    Sha256,
+    #[cfg(feature = "blake3")]
+    Blake3,
+}
+
+impl Checksum {
+    /// Compute the checksum of the content read until the end, streaming it in chunks
+    pub fn of_reader(&self, mut reader: impl Read) -> std::io::Result<Vec<u8>> {
+        let mut hasher = Hasher::new(*self);
+        let mut buffer = vec![0; CHUNK_SIZE];
+        loop {
+            match reader.read(&mut buffer) {
+                Ok(0) => break,
+                Ok(read) => hasher.update(&buffer[..read]),
+                Err(error) if error.kind() == std::io::ErrorKind::Interrupted => continue,
+                Err(error) => return Err(error),
+            }
+        }
+        Ok(hasher.finish())
+    }
+
+    /// Compute the checksum of a file within a file system
+    pub fn of_file(&self, file_system: &dyn FileSystem, file: &Path) -> std::io::Result<Vec<u8>> {
+        self.of_reader(file_system.open(file)?)
+    }
+}
+
+/// A predicate matching the files whose content has a given checksum
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ContentHash {
+    checksum: Checksum,
+    hex: String,
+}
+
+impl ContentHash {
+    /// Match the files with a given checksum written as a hexadecimal string, ignoring the case
+    pub fn new(checksum: Checksum, hex: impl Into<String>) -> Self {
+        Self {
+            checksum,
+            hex: hex.into().to_lowercase(),
+        }
+    }
+
+    pub fn checksum(&self) -> Checksum {
+        self.checksum
+    }
+
+    /// The expected checksum as a lowercase hexadecimal string
+    pub fn hex(&self) -> &str {
+        self.hex.as_str()
+    }
+
+    /// Return true if a given entry is a file with the expected checksum.
+    /// Folders and unreadable files never match
+    pub fn matches(&self, file_system: &dyn FileSystem, entry: &Path) -> bool {
+        match file_system.metadata(entry) {
+            Ok(metadata) if metadata.is_file() => self
+                .checksum
+                .of_file(file_system, entry)
+                .is_ok_and(|digest| to_hex(&digest) == self.hex),
+            _ => false,
+        }
+    }
+}
+
+/// Format a checksum as a lowercase hexadecimal string
+pub(crate) fn to_hex(digest: &[u8]) -> String {
+    digest.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+enum Hasher {
+    #[cfg(feature = "sha256")]
+    Sha256(sha2::Sha256),
+    #[cfg(feature = "blake3")]
+    Blake3(Box<blake3::Hasher>),
+}
+
+impl Hasher {
+    fn new(checksum: Checksum) -> Self {
+        match checksum {
+            #[cfg(feature = "sha256")]
+            Checksum::Sha256 => Hasher::Sha256(sha2::Digest::new()),
+            #[cfg(feature = "blake3")]
+            Checksum::Blake3 => Hasher::Blake3(Box::new(blake3::Hasher::new())),
+        }
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        match self {
+            #[cfg(feature = "sha256")]
+            Hasher::Sha256(hasher) => sha2::Digest::update(hasher, data),
+            #[cfg(feature = "blake3")]
+            Hasher::Blake3(hasher) => {
+                hasher.update(data);
+            }
+        }
+    }
+
+    fn finish(self) -> Vec<u8> {
+        match self {
+            #[cfg(feature = "sha256")]
+            Hasher::Sha256(hasher) => sha2::Digest::finalize(hasher).to_vec(),
+            #[cfg(feature = "blake3")]
+            Hasher::Blake3(hasher) => hasher.finalize().as_bytes().to_vec(),
+        }
+    }
+}
+
+/// Compare the checksums of a copied entry and its copy, folders are compared recursively.
+/// Fails if any copied file differs or is missing
+#[cfg(any(feature = "copier", feature = "mover"))]
+pub(crate) fn verify_copy(
+    checksum: Checksum,
+    from_file_system: &dyn FileSystem,
+    from: &Path,
+    to_file_system: &dyn FileSystem,
+    to: &Path,
+) -> Result<()> {
+    let metadata = from_file_system.metadata(from)?;
+
+    if metadata.is_file() {
+        let matches = match to_file_system.metadata(to) {
+            Ok(copy) if copy.is_file() => {
+                checksum.of_file(from_file_system, from)? == checksum.of_file(to_file_system, to)?
+            }
+            _ => false,
+        };
+        if !matches {
+            return FileMatcherError::ChecksumMismatch(from.to_path_buf(), to.to_path_buf()).into();
+        }
+    } else if metadata.is_folder() {
+        for each_entry in from_file_system.read_dir(from)? {
+            let each_entry = each_entry?;
+            if let Some(each_name) = each_entry.file_name() {
+                verify_copy(
+                    checksum,
+                    from_file_system,
+                    &each_entry,
+                    to_file_system,
+                    &to.join(each_name),
+                )?;
+            }
+        }
+    }
+    Ok(())
+}
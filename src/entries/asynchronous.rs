@@ -0,0 +1,45 @@
+#[cfg(not(feature = "async"))]
+compile_error!("Please select an async feature to build with async support");
+
+use crate::utils::spawn_blocking;
+use crate::{ManyEntries, OneEntry, Result};
+use std::path::PathBuf;
+
+/// Asynchronous versions of the [`OneEntry`] operations.
+/// The directory is read on the tokio blocking thread pool, with the same semantics
+/// and errors as the blocking operations
+impl OneEntry {
+    /// See [`OneEntry::exists`]
+    pub async fn exists_async(&self) -> Result<bool> {
+        Ok(self.find_optional_async().await?.is_some())
+    }
+
+    /// See [`OneEntry::find`]
+    pub async fn find_async(&self) -> Result<PathBuf> {
+        let entry = self.clone();
+        spawn_blocking(move || entry.find()).await
+    }
+
+    /// See [`OneEntry::find_optional`]
+    pub async fn find_optional_async(&self) -> Result<Option<PathBuf>> {
+        let entry = self.clone();
+        spawn_blocking(move || entry.find_optional()).await
+    }
+
+    /// See [`OneEntry::as_bytes`]
+    pub async fn as_bytes_async(&self) -> Result<Vec<u8>> {
+        let entry = self.clone();
+        spawn_blocking(move || entry.as_bytes()).await
+    }
+}
+
+/// Asynchronous versions of the [`ManyEntries`] operations.
+/// The directory is read on the tokio blocking thread pool, with the same semantics
+/// and errors as the blocking operations
+impl ManyEntries {
+    /// See [`ManyEntries::find`]
+    pub async fn find_async(&self) -> Result<Vec<PathBuf>> {
+        let entries = self.clone();
+        spawn_blocking(move || entries.find()).await
+    }
+}
@@ -0,0 +1,55 @@
+use std::fmt;
+
+/// Represents an expected amount of [`ManyEntries`](crate::ManyEntries),
+/// by default any amount of entries is expected.
+/// The minimum never exceeds the maximum: setting one bound past the other moves the other
+/// bound to the same value, so that `at_least(5).at_most(2)` expects exactly 2 entries
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub struct EntriesCount {
+    min: usize,
+    max: Option<usize>,
+}
+
+impl EntriesCount {
+    /// Create an expected amount of entries, a maximum below the minimum is raised to it
+    pub fn new(min: usize, max: Option<usize>) -> Self {
+        Self {
+            min,
+            max: max.map(|max| max.max(min)),
+        }
+    }
+
+    pub fn min(&self) -> usize {
+        self.min
+    }
+
+    pub fn max(&self) -> Option<usize> {
+        self.max
+    }
+
+    /// Return true if a given amount of entries is expected
+    pub fn accepts(&self, count: usize) -> bool {
+        count >= self.min && self.max.map_or(true, |max| count <= max)
+    }
+
+    /// The same maximum unless it is below a given minimum
+    pub(crate) fn at_least(&self, min: usize) -> Self {
+        Self::new(min, self.max)
+    }
+
+    /// The same minimum unless it is above a given maximum
+    pub(crate) fn at_most(&self, max: usize) -> Self {
+        Self::new(self.min.min(max), Some(max))
+    }
+}
+
+impl fmt::Display for EntriesCount {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.max {
+            None => write!(f, "at least {}", self.min),
+            Some(max) if max == self.min => write!(f, "exactly {}", max),
+            Some(max) if self.min == 0 => write!(f, "at most {}", max),
+            Some(max) => write!(f, "between {} and {}", self.min, max),
+        }
+    }
+}
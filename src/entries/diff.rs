@@ -0,0 +1,134 @@
+#[cfg(any(feature = "sha256", feature = "blake3"))]
+use crate::Checksum;
+use crate::{EntryMetadata, FileSystem, ManyEntries, Result};
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+
+/// How two files with the same relative path are compared to decide if they differ.
+/// Entries of different types always differ, folders of the same path never do
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub enum DiffComparison {
+    /// The sizes differ
+    #[default]
+    Size,
+    /// The modification times differ
+    Modified,
+    /// The sizes or the modification times differ
+    SizeAndModified,
+    /// The checksums of the contents differ
+    #[cfg(any(feature = "sha256", feature = "blake3"))]
+    Checksum(Checksum),
+}
+
+/// The differences between the entries found within two folders,
+/// the paths are relative to the compared folders and sorted
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct EntriesDiff {
+    added: Vec<PathBuf>,
+    removed: Vec<PathBuf>,
+    changed: Vec<PathBuf>,
+}
+
+impl EntriesDiff {
+    /// Entries found only within the other folder
+    pub fn added(&self) -> &[PathBuf] {
+        self.added.as_slice()
+    }
+
+    /// Entries found only within the searched folder
+    pub fn removed(&self) -> &[PathBuf] {
+        self.removed.as_slice()
+    }
+
+    /// Entries found within both folders that differ
+    pub fn changed(&self) -> &[PathBuf] {
+        self.changed.as_slice()
+    }
+
+    /// Return true if both folders have the same entries
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// Comparison of the entries found within the searched folder with the entries
+/// found by the same pattern and options within another folder
+impl ManyEntries {
+    /// Compare the found entries with the ones within another folder by their sizes
+    pub fn diff(&self, other_directory: impl Into<PathBuf>) -> Result<EntriesDiff> {
+        self.diff_by(other_directory, DiffComparison::default())
+    }
+
+    /// Compare the found entries with the ones within another folder.
+    /// Returns [`crate::FileMatcherError::WrongCount`] if the amount of entries found
+    /// within either folder is not expected
+    pub fn diff_by(
+        &self,
+        other_directory: impl Into<PathBuf>,
+        comparison: DiffComparison,
+    ) -> Result<EntriesDiff> {
+        let other = self.within_directory(other_directory.into());
+
+        let entries = relative_entries(self)?;
+        let other_entries = relative_entries(&other)?;
+
+        let mut diff = EntriesDiff::default();
+        for each in entries.difference(&other_entries) {
+            diff.removed.push(each.clone());
+        }
+        for each in other_entries.difference(&entries) {
+            diff.added.push(each.clone());
+        }
+        for each in entries.intersection(&other_entries) {
+            if differ(
+                self.file_system(),
+                &self.directory().join(each),
+                &other.directory().join(each),
+                comparison,
+            )? {
+                diff.changed.push(each.clone());
+            }
+        }
+        Ok(diff)
+    }
+}
+
+/// The found entries relative to the searched folder
+fn relative_entries(entries: &ManyEntries) -> Result<BTreeSet<PathBuf>> {
+    Ok(entries
+        .find()?
+        .into_iter()
+        .map(|each| match each.strip_prefix(entries.directory()) {
+            Ok(relative) => relative.to_path_buf(),
+            Err(_) => each,
+        })
+        .collect())
+}
+
+fn differ(
+    file_system: &dyn FileSystem,
+    entry: &Path,
+    other_entry: &Path,
+    comparison: DiffComparison,
+) -> Result<bool> {
+    let metadata = file_system.metadata(entry)?;
+    let other_metadata = file_system.metadata(other_entry)?;
+
+    if metadata.is_folder() || other_metadata.is_folder() {
+        return Ok(metadata.is_folder() != other_metadata.is_folder());
+    }
+
+    let differ_in_size = |a: &EntryMetadata, b: &EntryMetadata| a.len() != b.len();
+    let differ_in_time = |a: &EntryMetadata, b: &EntryMetadata| a.modified() != b.modified();
+
+    match comparison {
+        DiffComparison::Size => Ok(differ_in_size(&metadata, &other_metadata)),
+        DiffComparison::Modified => Ok(differ_in_time(&metadata, &other_metadata)),
+        DiffComparison::SizeAndModified => Ok(differ_in_size(&metadata, &other_metadata)
+            || differ_in_time(&metadata, &other_metadata)),
+        #[cfg(any(feature = "sha256", feature = "blake3"))]
+        DiffComparison::Checksum(checksum) => Ok(differ_in_size(&metadata, &other_metadata)
+            || checksum.of_file(file_system, entry)?
+                != checksum.of_file(file_system, other_entry)?),
+    }
+}
@@ -0,0 +1,101 @@
+#[cfg(not(any(feature = "sha256", feature = "blake3")))]
+compile_error!("Please select a sha256 or blake3 feature to build with duplicates support");
+
+#[cfg(feature = "linker")]
+use crate::utils::temporary_sibling;
+#[cfg(feature = "linker")]
+use crate::FileSystem;
+use crate::{Checksum, ManyEntries, Result};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// A group of files with identical contents, sorted by their path
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct DuplicateFiles {
+    size: u64,
+    files: Vec<PathBuf>,
+}
+
+impl DuplicateFiles {
+    /// The size of each of the files in bytes
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    /// All identical files, at least two
+    pub fn files(&self) -> &[PathBuf] {
+        self.files.as_slice()
+    }
+
+    /// The first of the identical files, the one that is kept when duplicates are linked
+    pub fn original(&self) -> &Path {
+        self.files[0].as_path()
+    }
+
+    /// The identical files except the original
+    pub fn duplicates(&self) -> &[PathBuf] {
+        &self.files[1..]
+    }
+}
+
+/// Detection of the found files with identical contents
+impl ManyEntries {
+    /// Group the found files with identical contents. The files are grouped by size first,
+    /// only the files of the same size are read to compare their checksums.
+    /// Folders and empty files are ignored. The groups are sorted by their original
+    pub fn duplicates(&self, checksum: Checksum) -> Result<Vec<DuplicateFiles>> {
+        let mut by_size = BTreeMap::<u64, Vec<PathBuf>>::new();
+        for entry in self.find()? {
+            let metadata = self.file_system().metadata(&entry)?;
+            if metadata.is_file() && !metadata.is_empty() {
+                by_size.entry(metadata.len()).or_default().push(entry);
+            }
+        }
+
+        let mut duplicates = vec![];
+        for (size, files) in by_size {
+            if files.len() < 2 {
+                continue;
+            }
+            let mut by_checksum = BTreeMap::<Vec<u8>, Vec<PathBuf>>::new();
+            for file in files {
+                let digest = checksum.of_file(self.file_system(), &file)?;
+                by_checksum.entry(digest).or_default().push(file);
+            }
+            for (_, mut files) in by_checksum {
+                if files.len() > 1 {
+                    files.sort();
+                    duplicates.push(DuplicateFiles { size, files });
+                }
+            }
+        }
+        duplicates.sort_by(|a, b| a.files.cmp(&b.files));
+        Ok(duplicates)
+    }
+
+    /// Replace every duplicate by a hard link to its original, returns the linked groups.
+    /// Each duplicate is replaced at once by renaming a link created next to it,
+    /// so that a failure leaves the duplicate in place
+    #[cfg(feature = "linker")]
+    pub fn link_duplicates(&self, checksum: Checksum) -> Result<Vec<DuplicateFiles>> {
+        let duplicates = self.duplicates(checksum)?;
+        for group in &duplicates {
+            for duplicate in group.duplicates() {
+                replace_by_hard_link(self.file_system(), group.original(), duplicate)?;
+            }
+        }
+        Ok(duplicates)
+    }
+}
+
+#[cfg(feature = "linker")]
+fn replace_by_hard_link(file_system: &dyn FileSystem, original: &Path, file: &Path) -> Result<()> {
+    let link = temporary_sibling(file);
+    file_system.hard_link(original, &link)?;
+    if let Err(error) = file_system.rename(&link, file) {
+        // the error of the rename is more relevant than the one of the cleanup
+        let _ = file_system.remove_file(&link);
+        return Err(error.into());
+    }
+    Ok(())
+}
@@ -1,7 +1,10 @@
 use std::fmt::Debug;
 
+#[cfg(any(feature = "sha256", feature = "blake3"))]
+use crate::ContentHash;
+
 #[cfg(feature = "serde")]
-use serde::{Serialize, Deserialize};
+use serde::{Deserialize, Serialize};
 
 /// Represents an entry name that can be of different types
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -31,6 +34,12 @@ pub enum EntryType {
 pub(crate) struct EntryNamed {
     entry_name: EntryName,
     entry_type: EntryType,
+    #[cfg(any(feature = "sha256", feature = "blake3"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    content_hash: Option<ContentHash>,
 }
 
 impl EntryNamed {
@@ -38,6 +47,8 @@ impl EntryNamed {
         Self {
             entry_name,
             entry_type,
+            #[cfg(any(feature = "sha256", feature = "blake3"))]
+            content_hash: None,
         }
     }
 
@@ -60,4 +71,31 @@ impl EntryNamed {
     pub fn entry_type(&self) -> &EntryType {
         &self.entry_type
     }
+
+    #[cfg(any(feature = "sha256", feature = "blake3"))]
+    pub fn with_content_hash(mut self, content_hash: ContentHash) -> Self {
+        self.content_hash = Some(content_hash);
+        self
+    }
+
+    #[cfg(any(feature = "sha256", feature = "blake3"))]
+    pub fn content_hash(&self) -> Option<&ContentHash> {
+        self.content_hash.as_ref()
+    }
 }
+
+/// Bounds of the named entries that depend on the enabled features.
+/// With the `async` feature the named entries are moved to the tokio blocking thread pool,
+/// so they must be [`Send`] and [`Sync`], otherwise there are no additional bounds
+#[cfg(feature = "async")]
+pub trait MaybeSendSync: Send + Sync {}
+#[cfg(feature = "async")]
+impl<T: Send + Sync + ?Sized> MaybeSendSync for T {}
+
+/// Bounds of the named entries that depend on the enabled features.
+/// With the `async` feature the named entries are moved to the tokio blocking thread pool,
+/// so they must be [`Send`] and [`Sync`], otherwise there are no additional bounds
+#[cfg(not(feature = "async"))]
+pub trait MaybeSendSync {}
+#[cfg(not(feature = "async"))]
+impl<T: ?Sized> MaybeSendSync for T {}
@@ -0,0 +1,32 @@
+#[cfg(not(any(feature = "sha256", feature = "blake3")))]
+compile_error!("Please select a sha256 or blake3 feature to build with hashing support");
+
+use crate::checksum::to_hex;
+use crate::{Checksum, OneEntry, Result};
+
+/// Checksums of the found file. The file is streamed in chunks instead of
+/// being read into memory like [`OneEntry::as_bytes`]
+impl OneEntry {
+    /// Find the file and compute its checksum
+    pub fn checksum(&self, checksum: Checksum) -> Result<Vec<u8>> {
+        let path = self.find()?;
+        Ok(checksum.of_file(self.file_system(), &path)?)
+    }
+
+    /// Find the file and compute its checksum as a lowercase hexadecimal string
+    pub fn checksum_hex(&self, checksum: Checksum) -> Result<String> {
+        Ok(to_hex(&self.checksum(checksum)?))
+    }
+
+    /// Find the file and compute its SHA-256 checksum
+    #[cfg(feature = "sha256")]
+    pub fn sha256(&self) -> Result<Vec<u8>> {
+        self.checksum(Checksum::Sha256)
+    }
+
+    /// Find the file and compute its BLAKE3 checksum
+    #[cfg(feature = "blake3")]
+    pub fn blake3(&self) -> Result<Vec<u8>> {
+        self.checksum(Checksum::Blake3)
+    }
+}
@@ -1,88 +1,325 @@
+use crate::finders::name_matcher::EntryNameMatcher;
+use crate::finders::recursive_finder::RecursiveEntries;
 use crate::utils::is_readable_entry;
-use crate::{EntryName, EntryType, Result};
+#[cfg(any(feature = "sha256", feature = "blake3"))]
+use crate::{Checksum, ContentHash};
+use crate::{
+    EntriesCount, EntriesOrder, EntryName, EntryType, FileMatcherError, FileSystem, MaybeSendSync,
+    Result, StdFileSystem,
+};
 use std::fmt::Debug;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
-pub trait ManyEntriesNamed: Debug {
+pub trait ManyEntriesNamed: Debug + MaybeSendSync {
     fn within_path_buf(&self, directory: PathBuf) -> ManyEntries;
     fn entry_name(&self) -> &EntryName;
     fn entry_type(&self) -> &EntryType;
     fn name_alias(&self) -> Option<&str>;
     fn boxed(&self) -> Box<dyn ManyEntriesNamed>;
+
+    /// The checksum the content of the matching files must have, applied to the found entries
+    #[cfg(any(feature = "sha256", feature = "blake3"))]
+    fn content_hash(&self) -> Option<&ContentHash> {
+        None
+    }
 }
 
 #[derive(Debug)]
 pub struct ManyEntries {
     entries_named: Box<dyn ManyEntriesNamed>,
     directory: PathBuf,
+    file_system: Arc<dyn FileSystem>,
+    recursive: bool,
+    #[cfg(feature = "parallel")]
+    parallel: bool,
+    order: Option<EntriesOrder>,
+    count: EntriesCount,
+    #[cfg(any(feature = "sha256", feature = "blake3"))]
+    content_hash: Option<Arc<ContentHash>>,
 }
 
 impl ManyEntries {
     pub fn new(entries_named: Box<dyn ManyEntriesNamed>, directory: impl Into<PathBuf>) -> Self {
+        #[cfg(any(feature = "sha256", feature = "blake3"))]
+        let content_hash = entries_named.content_hash().cloned().map(Arc::new);
         Self {
             entries_named,
             directory: directory.into(),
+            file_system: Arc::new(StdFileSystem),
+            recursive: false,
+            #[cfg(feature = "parallel")]
+            parallel: false,
+            order: None,
+            count: EntriesCount::default(),
+            #[cfg(any(feature = "sha256", feature = "blake3"))]
+            content_hash,
         }
     }
 
+    /// Search within a given file system instead of [`StdFileSystem`]
+    pub fn with_file_system(mut self, file_system: impl FileSystem + 'static) -> Self {
+        self.file_system = Arc::new(file_system);
+        self
+    }
+
+    pub fn file_system(&self) -> &dyn FileSystem {
+        self.file_system.as_ref()
+    }
+
+    #[cfg(feature = "transaction")]
+    pub(crate) fn shared_file_system(&self) -> Arc<dyn FileSystem> {
+        self.file_system.clone()
+    }
+
+    /// Only match the files whose content has a given checksum, written as a hexadecimal string.
+    /// The candidate files are streamed to compute their checksums
+    #[cfg(any(feature = "sha256", feature = "blake3"))]
+    pub fn with_content_hash(mut self, checksum: Checksum, hex: impl Into<String>) -> Self {
+        self.content_hash = Some(Arc::new(ContentHash::new(checksum, hex)));
+        self
+    }
+
+    #[cfg(any(feature = "sha256", feature = "blake3"))]
+    pub fn content_hash(&self) -> Option<&ContentHash> {
+        self.content_hash.as_deref()
+    }
+
+    /// Also search within all nested folders.
+    /// Symbolic links to folders are not followed
+    pub fn recursive(mut self) -> Self {
+        self.recursive = true;
+        self
+    }
+
+    /// Return true if the entries are also searched within nested folders
+    pub fn is_recursive(&self) -> bool {
+        self.recursive
+    }
+
+    /// Read nested folders in parallel using the rayon thread pool when searching recursively,
+    /// a single folder is read on the current thread. The entries are found
+    /// in no particular order, use [`ManyEntries::sorted`] to get a deterministic result
+    #[cfg(feature = "parallel")]
+    pub fn parallel(mut self) -> Self {
+        self.parallel = true;
+        self
+    }
+
+    /// Return the entries sorted by their path, so that the result does not depend
+    /// on the order in which the file system lists the folder entries.
+    /// See [`ManyEntries::sorted_by`]
+    pub fn sorted(self) -> Self {
+        self.sorted_by(EntriesOrder::Path)
+    }
+
+    /// Return the entries in a given order.
+    /// The entries are collected before iteration, errors come first
+    pub fn sorted_by(mut self, order: EntriesOrder) -> Self {
+        self.order = Some(order);
+        self
+    }
+
+    /// Return the order in which the entries are returned,
+    /// or None if the order is unspecified
+    pub fn order(&self) -> Option<EntriesOrder> {
+        self.order
+    }
+
+    /// Expect to find at least a given amount of entries,
+    /// raises the maximum amount if it is lower
+    pub fn at_least(mut self, min: usize) -> Self {
+        self.count = self.count.at_least(min);
+        self
+    }
+
+    /// Expect to find at most a given amount of entries,
+    /// lowers the minimum amount if it is higher
+    pub fn at_most(mut self, max: usize) -> Self {
+        self.count = self.count.at_most(max);
+        self
+    }
+
+    /// Expect to find exactly a given amount of entries
+    pub fn exactly(self, count: usize) -> Self {
+        self.at_least(count).at_most(count)
+    }
+
+    /// Expect to find at least one entry
+    pub fn non_empty(self) -> Self {
+        self.at_least(1)
+    }
+
+    /// Return the expected amount of entries
+    pub fn count(&self) -> &EntriesCount {
+        &self.count
+    }
+
+    pub fn entry_type(&self) -> &EntryType {
+        self.entries_named.entry_type()
+    }
+
+    pub fn entry_name(&self) -> &EntryName {
+        self.entries_named.entry_name()
+    }
+
+    pub fn directory(&self) -> &Path {
+        self.directory.as_path()
+    }
+
+    /// The same search within another folder
+    pub(crate) fn within_directory(&self, directory: PathBuf) -> Self {
+        let mut entries = self.clone();
+        entries.directory = directory;
+        entries
+    }
+
+    /// Find all entries of the specified type and name.
+    /// Unless an order is set with [`ManyEntries::sorted_by`] the entries are returned
+    /// in the order in which the file system lists them, which is unspecified.
+    /// Returns [`FileMatcherError::WrongCount`] if the amount of found entries is not expected
     pub fn find(&self) -> Result<Vec<PathBuf>> {
-        let entry_type = self.entries_named.entry_type();
-        let entry_name = self.entries_named.entry_name();
+        let entries = self.iter().collect::<Result<Vec<PathBuf>>>()?;
+        if self.count.accepts(entries.len()) {
+            Ok(entries)
+        } else {
+            FileMatcherError::WrongCount(self.clone(), entries).into()
+        }
+    }
+
+    /// Lazily iterate over the entries of the specified type and name.
+    /// The directory is read on demand, which allows callers to stop early.
+    /// Entries that failed to be read are returned as errors.
+    /// The expected amount of entries is not checked
+    pub fn iter(&self) -> ManyEntriesIter {
+        let entries = self.entries();
 
-        self.find_by_type_and_name(entry_type, entry_name)
-    }
-
-    fn find_by_type_and_name(
-        &self,
-        entry_type: &EntryType,
-        entry_name: &EntryName,
-    ) -> Result<Vec<PathBuf>> {
-        match entry_name {
-            EntryName::Exact(name) => {
-                let entry = self.directory.join(name);
-                if is_readable_entry(entry_type, &entry) {
-                    Ok(vec![entry])
-                } else {
-                    Ok(vec![])
+        if let Some(order) = &self.order {
+            let mut errors = vec![];
+            let mut found = vec![];
+            for each_entry in entries {
+                match each_entry {
+                    Ok(each_path) => found.push(each_path),
+                    Err(error) => errors.push(Err(error)),
                 }
             }
-            EntryName::Any(names) => {
-                let entries = names
-                    .iter()
-                    .map(|each| self.directory.join(each))
-                    .filter(|each| is_readable_entry(entry_type, each.as_path()))
-                    .collect::<Vec<PathBuf>>();
-
-                Ok(entries)
-            }
-            EntryName::AnyNamed(entry_names) => {
-                let mut entries: Vec<PathBuf> = vec![];
+            order.sort(self.file_system(), &mut found);
+            ManyEntriesIter::new(Box::new(
+                errors.into_iter().chain(found.into_iter().map(Ok)),
+            ))
+        } else {
+            ManyEntriesIter::new(entries)
+        }
+    }
 
-                for entry_name in entry_names {
-                    for each_path in self.find_by_type_and_name(entry_type, entry_name)? {
-                        entries.push(each_path);
-                    }
-                }
+    fn entries(&self) -> Box<dyn Iterator<Item = Result<PathBuf>>> {
+        let entries = self.named_entries();
 
-                Ok(entries)
-            }
-            #[cfg(feature = "regex")]
-            EntryName::Regex(regex_pattern) => {
-                let entries = crate::finders::regex_finder::find_entries_in_directory_matching(
+        #[cfg(any(feature = "sha256", feature = "blake3"))]
+        if let Some(content_hash) = self.content_hash.clone() {
+            let file_system = self.file_system.clone();
+            return Box::new(entries.filter(move |each| match each {
+                Ok(each) => content_hash.matches(file_system.as_ref(), each),
+                Err(_) => true,
+            }));
+        }
+
+        entries
+    }
+
+    fn named_entries(&self) -> Box<dyn Iterator<Item = Result<PathBuf>>> {
+        let entry_type = self.entries_named.entry_type();
+        let entry_name = self.entries_named.entry_name();
+
+        #[cfg(feature = "parallel")]
+        if self.parallel && self.recursive {
+            return match EntryNameMatcher::new(entry_name) {
+                Ok(matcher) => Box::new(crate::finders::parallel_finder::ParallelEntries::new(
+                    &self.file_system,
                     entry_type,
-                    regex_pattern,
-                    &self.directory,
-                )?;
-                Ok(entries)
-            }
-            #[cfg(feature = "wildmatch")]
-            EntryName::Wildmatch(wildmatch_pattern) => {
-                let entries = crate::finders::wildmatch_finder::find_entries_in_directory_matching(
+                    matcher,
+                    self.directory.clone(),
+                )),
+                Err(error) => Box::new(std::iter::once(Err(error))),
+            };
+        }
+
+        if self.recursive {
+            match EntryNameMatcher::new(entry_name) {
+                Ok(matcher) => Box::new(RecursiveEntries::new(
+                    &self.file_system,
                     entry_type,
-                    wildmatch_pattern,
-                    &self.directory,
-                )?;
-                Ok(entries)
+                    matcher,
+                    self.directory.clone(),
+                )),
+                Err(error) => Box::new(std::iter::once(Err(error))),
+            }
+        } else {
+            entries_by_type_and_name(&self.file_system, &self.directory, entry_type, entry_name)
+        }
+    }
+}
+
+fn entries_by_type_and_name(
+    file_system: &Arc<dyn FileSystem>,
+    directory: &Path,
+    entry_type: &EntryType,
+    entry_name: &EntryName,
+) -> Box<dyn Iterator<Item = Result<PathBuf>>> {
+    match entry_name {
+        EntryName::Exact(name) => {
+            let file_system = file_system.clone();
+            let entry_type = entry_type.clone();
+            let entry = directory.join(name);
+            Box::new(
+                std::iter::once(entry)
+                    .filter(move |each| is_readable_entry(file_system.as_ref(), &entry_type, each))
+                    .map(Ok),
+            )
+        }
+        EntryName::Any(names) => {
+            let file_system = file_system.clone();
+            let entry_type = entry_type.clone();
+            let directory = directory.to_path_buf();
+            Box::new(
+                names
+                    .clone()
+                    .into_iter()
+                    .map(move |each| directory.join(each))
+                    .filter(move |each| is_readable_entry(file_system.as_ref(), &entry_type, each))
+                    .map(Ok),
+            )
+        }
+        EntryName::AnyNamed(entry_names) => {
+            let file_system = file_system.clone();
+            let entry_type = entry_type.clone();
+            let directory = directory.to_path_buf();
+            Box::new(entry_names.clone().into_iter().flat_map(move |entry_name| {
+                entries_by_type_and_name(&file_system, &directory, &entry_type, &entry_name)
+            }))
+        }
+        #[cfg(feature = "regex")]
+        EntryName::Regex(regex_pattern) => {
+            match crate::finders::regex_finder::entries_in_directory_matching(
+                file_system,
+                entry_type,
+                regex_pattern,
+                directory.to_path_buf(),
+            ) {
+                Ok(entries) => Box::new(entries),
+                Err(error) => Box::new(std::iter::once(Err(error))),
+            }
+        }
+        #[cfg(feature = "wildmatch")]
+        EntryName::Wildmatch(wildmatch_pattern) => {
+            match crate::finders::wildmatch_finder::entries_in_directory_matching(
+                file_system,
+                entry_type,
+                wildmatch_pattern,
+                directory.to_path_buf(),
+            ) {
+                Ok(entries) => Box::new(entries),
+                Err(error) => Box::new(std::iter::once(Err(error))),
             }
         }
     }
@@ -90,6 +327,61 @@ impl ManyEntries {
 
 impl Clone for ManyEntries {
     fn clone(&self) -> Self {
-        Self::new(self.entries_named.boxed(), self.directory.clone())
+        Self {
+            entries_named: self.entries_named.boxed(),
+            directory: self.directory.clone(),
+            file_system: self.file_system.clone(),
+            recursive: self.recursive,
+            #[cfg(feature = "parallel")]
+            parallel: self.parallel,
+            order: self.order,
+            count: self.count,
+            #[cfg(any(feature = "sha256", feature = "blake3"))]
+            content_hash: self.content_hash.clone(),
+        }
+    }
+}
+
+/// A lazy iterator over the entries matching [`ManyEntries`].
+/// Yields an error if the name pattern is invalid or an entry could not be read
+pub struct ManyEntriesIter {
+    entries: Box<dyn Iterator<Item = Result<PathBuf>>>,
+}
+
+impl ManyEntriesIter {
+    fn new(entries: Box<dyn Iterator<Item = Result<PathBuf>>>) -> Self {
+        Self { entries }
+    }
+}
+
+impl Iterator for ManyEntriesIter {
+    type Item = Result<PathBuf>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.entries.next()
+    }
+}
+
+impl Debug for ManyEntriesIter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ManyEntriesIter").finish()
+    }
+}
+
+impl IntoIterator for ManyEntries {
+    type Item = Result<PathBuf>;
+    type IntoIter = ManyEntriesIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl IntoIterator for &ManyEntries {
+    type Item = Result<PathBuf>;
+    type IntoIter = ManyEntriesIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
     }
 }
@@ -1,9 +1,28 @@
+#[cfg(feature = "async")]
+mod asynchronous;
+mod count;
+mod diff;
+#[cfg(any(feature = "sha256", feature = "blake3"))]
+mod duplicates;
 mod entry;
+#[cfg(any(feature = "sha256", feature = "blake3"))]
+mod hashing;
 mod many;
 mod one;
+mod order;
+mod wait;
+#[cfg(feature = "watch")]
+mod watch;
 
+pub use count::EntriesCount;
+pub use diff::{DiffComparison, EntriesDiff};
+#[cfg(any(feature = "sha256", feature = "blake3"))]
+pub use duplicates::DuplicateFiles;
 pub(crate) use entry::EntryNamed;
-pub use entry::{EntryName, EntryType};
+pub use entry::{EntryName, EntryType, MaybeSendSync};
 
-pub use many::{ManyEntries, ManyEntriesNamed};
+pub use many::{ManyEntries, ManyEntriesIter, ManyEntriesNamed};
 pub use one::{OneEntry, OneEntryNamed};
+pub use order::EntriesOrder;
+#[cfg(feature = "watch")]
+pub use watch::{EntriesWatcher, WatchEvent};
@@ -1,32 +1,79 @@
 use crate::utils::is_readable_entry;
-use crate::{EntryName, EntryType, FileMatcherError, Result};
+#[cfg(any(feature = "sha256", feature = "blake3"))]
+use crate::{Checksum, ContentHash};
+use crate::{
+    EntryName, EntryType, FileMatcherError, FileSystem, MaybeSendSync, Result, StdFileSystem,
+};
 use std::ffi::OsString;
 use std::fmt::Debug;
 use std::io::Read;
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
-pub trait OneEntryNamed: Debug {
+pub trait OneEntryNamed: Debug + MaybeSendSync {
     fn within_path_buf(&self, directory: PathBuf) -> OneEntry;
     fn entry_name(&self) -> &EntryName;
     fn entry_type(&self) -> &EntryType;
     fn name_alias(&self) -> Option<&str>;
     fn boxed(&self) -> Box<dyn OneEntryNamed>;
+
+    /// The checksum the content of the matching files must have, applied to the found entries
+    #[cfg(any(feature = "sha256", feature = "blake3"))]
+    fn content_hash(&self) -> Option<&ContentHash> {
+        None
+    }
 }
 
 #[derive(Debug)]
 pub struct OneEntry {
     entry_named: Box<dyn OneEntryNamed>,
     directory: PathBuf,
+    file_system: Arc<dyn FileSystem>,
+    #[cfg(any(feature = "sha256", feature = "blake3"))]
+    content_hash: Option<Arc<ContentHash>>,
 }
 
 impl OneEntry {
     pub fn new(entry_named: Box<dyn OneEntryNamed>, directory: impl Into<PathBuf>) -> Self {
+        #[cfg(any(feature = "sha256", feature = "blake3"))]
+        let content_hash = entry_named.content_hash().cloned().map(Arc::new);
         Self {
             entry_named,
             directory: directory.into(),
+            file_system: Arc::new(StdFileSystem),
+            #[cfg(any(feature = "sha256", feature = "blake3"))]
+            content_hash,
         }
     }
 
+    /// Search and operate on the entry within a given file system instead of [`StdFileSystem`]
+    pub fn with_file_system(mut self, file_system: impl FileSystem + 'static) -> Self {
+        self.file_system = Arc::new(file_system);
+        self
+    }
+
+    pub fn file_system(&self) -> &dyn FileSystem {
+        self.file_system.as_ref()
+    }
+
+    #[cfg(feature = "transaction")]
+    pub(crate) fn shared_file_system(&self) -> Arc<dyn FileSystem> {
+        self.file_system.clone()
+    }
+
+    /// Only match a file whose content has a given checksum, written as a hexadecimal string.
+    /// The candidate files are streamed to compute their checksums
+    #[cfg(any(feature = "sha256", feature = "blake3"))]
+    pub fn with_content_hash(mut self, checksum: Checksum, hex: impl Into<String>) -> Self {
+        self.content_hash = Some(Arc::new(ContentHash::new(checksum, hex)));
+        self
+    }
+
+    #[cfg(any(feature = "sha256", feature = "blake3"))]
+    pub fn content_hash(&self) -> Option<&ContentHash> {
+        self.content_hash.as_deref()
+    }
+
     pub fn entry(&self) -> &dyn OneEntryNamed {
         self.entry_named.as_ref()
     }
@@ -46,10 +93,16 @@ impl OneEntry {
     /// Return true if there exists exactly one entry of the specified type and name,
     /// false otherwise
     pub fn exists(&self) -> Result<bool> {
+        Ok(self.find_optional()?.is_some())
+    }
+
+    /// Try to find an exactly one entry of the specified type and name,
+    /// returns None if there is no such entry
+    pub fn find_optional(&self) -> Result<Option<PathBuf>> {
         match self.find() {
-            Ok(path) => Ok(path.exists()),
+            Ok(path) => Ok(Some(path)),
             Err(error) => match &error {
-                FileMatcherError::NotExists(_) => Ok(false),
+                FileMatcherError::NotExists(_) => Ok(None),
                 _ => error.into(),
             },
         }
@@ -63,6 +116,16 @@ impl OneEntry {
         self.find_by_type_and_name(entry_type, entry_name)
     }
 
+    /// Return true if the entry satisfies the content predicate, if any
+    fn matches_content(&self, entry: &Path) -> bool {
+        #[cfg(any(feature = "sha256", feature = "blake3"))]
+        if let Some(content_hash) = &self.content_hash {
+            return content_hash.matches(self.file_system(), entry);
+        }
+        let _ = entry;
+        true
+    }
+
     fn find_by_type_and_name(
         &self,
         entry_type: &EntryType,
@@ -71,7 +134,9 @@ impl OneEntry {
         match entry_name {
             EntryName::Exact(name) => {
                 let entry = self.directory.join(name);
-                if is_readable_entry(entry_type, &entry) {
+                if is_readable_entry(self.file_system(), entry_type, &entry)
+                    && self.matches_content(&entry)
+                {
                     Ok(entry)
                 } else {
                     FileMatcherError::NotExists(self.clone()).into()
@@ -81,7 +146,10 @@ impl OneEntry {
                 let entries = names
                     .iter()
                     .map(|each| self.directory.join(each))
-                    .filter(|each| is_readable_entry(entry_type, each.as_path()))
+                    .filter(|each| {
+                        is_readable_entry(self.file_system(), entry_type, each.as_path())
+                            && self.matches_content(each)
+                    })
                     .collect::<Vec<PathBuf>>();
 
                 match entries.len() {
@@ -115,10 +183,14 @@ impl OneEntry {
             #[cfg(feature = "regex")]
             EntryName::Regex(regex_pattern) => {
                 let entries = crate::finders::regex_finder::find_entries_in_directory_matching(
+                    &self.file_system,
                     entry_type,
                     regex_pattern,
                     &self.directory,
-                )?;
+                )?
+                .into_iter()
+                .filter(|each| self.matches_content(each))
+                .collect::<Vec<PathBuf>>();
                 match entries.len() {
                     0 => FileMatcherError::NotExists(self.clone()).into(),
                     1 => Ok(entries.first().unwrap().to_owned()),
@@ -128,10 +200,14 @@ impl OneEntry {
             #[cfg(feature = "wildmatch")]
             EntryName::Wildmatch(wildmatch_pattern) => {
                 let entries = crate::finders::wildmatch_finder::find_entries_in_directory_matching(
+                    &self.file_system,
                     entry_type,
                     wildmatch_pattern,
                     &self.directory,
-                )?;
+                )?
+                .into_iter()
+                .filter(|each| self.matches_content(each))
+                .collect::<Vec<PathBuf>>();
                 match entries.len() {
                     0 => FileMatcherError::NotExists(self.clone()).into(),
                     1 => Ok(entries.first().unwrap().to_owned()),
@@ -163,7 +239,7 @@ impl OneEntry {
 
     pub fn as_bytes(&self) -> Result<Vec<u8>> {
         let path = self.find()?;
-        let mut file = std::fs::File::open(path.as_path())?;
+        let mut file = self.file_system.open(path.as_path())?;
         let mut buffer = Vec::new();
         file.read_to_end(&mut buffer)?;
         Ok(buffer)
@@ -172,7 +248,13 @@ impl OneEntry {
 
 impl Clone for OneEntry {
     fn clone(&self) -> Self {
-        Self::new(self.entry_named.boxed(), self.directory.clone())
+        Self {
+            entry_named: self.entry_named.boxed(),
+            directory: self.directory.clone(),
+            file_system: self.file_system.clone(),
+            #[cfg(any(feature = "sha256", feature = "blake3"))]
+            content_hash: self.content_hash.clone(),
+        }
     }
 }
 
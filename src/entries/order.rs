@@ -0,0 +1,107 @@
+use crate::FileSystem;
+use std::cmp::Ordering;
+use std::ffi::OsStr;
+use std::path::PathBuf;
+
+/// Represents an order in which [`ManyEntries`](crate::ManyEntries) are returned.
+/// Entries that are equal according to the order are sorted by their path,
+/// which makes the order deterministic
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum EntriesOrder {
+    /// By the path, component by component
+    Path,
+    /// By the file name
+    Name,
+    /// By the file name, comparing sequences of digits by their numeric value,
+    /// so that `file2` comes before `file10`
+    NaturalName,
+    /// By the modification time, oldest first
+    ModificationTime,
+    /// By the size in bytes, smallest first
+    Size,
+    /// By the amount of path components, shallowest first
+    Depth,
+}
+
+impl EntriesOrder {
+    pub(crate) fn sort(&self, file_system: &dyn FileSystem, entries: &mut [PathBuf]) {
+        match self {
+            EntriesOrder::Path => entries.sort(),
+            EntriesOrder::Name => {
+                entries.sort_by(|a, b| a.file_name().cmp(&b.file_name()).then_with(|| a.cmp(b)))
+            }
+            EntriesOrder::NaturalName => entries.sort_by(|a, b| {
+                natural_cmp(
+                    a.file_name().unwrap_or_default(),
+                    b.file_name().unwrap_or_default(),
+                )
+                .then_with(|| a.cmp(b))
+            }),
+            EntriesOrder::ModificationTime => entries.sort_by_cached_key(|each| {
+                let modified = file_system
+                    .metadata(each)
+                    .ok()
+                    .and_then(|metadata| metadata.modified());
+                (modified, each.clone())
+            }),
+            EntriesOrder::Size => entries.sort_by_cached_key(|each| {
+                let size = file_system
+                    .metadata(each)
+                    .map(|metadata| metadata.len())
+                    .ok();
+                (size, each.clone())
+            }),
+            EntriesOrder::Depth => {
+                entries.sort_by_cached_key(|each| (each.components().count(), each.clone()))
+            }
+        }
+    }
+}
+
+/// Compares two names splitting them in sequences of digits and other characters.
+/// Sequences of digits are compared by their numeric value, the rest lexicographically
+fn natural_cmp(a: &OsStr, b: &OsStr) -> Ordering {
+    let a = a.to_string_lossy();
+    let b = b.to_string_lossy();
+    let mut a = a.chars().peekable();
+    let mut b = b.chars().peekable();
+
+    loop {
+        match (a.peek().copied(), b.peek().copied()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(a_char), Some(b_char)) if a_char.is_ascii_digit() && b_char.is_ascii_digit() => {
+                let a_digits = take_digits(&mut a);
+                let b_digits = take_digits(&mut b);
+                let a_number = a_digits.trim_start_matches('0');
+                let b_number = b_digits.trim_start_matches('0');
+
+                let ordering = a_number
+                    .len()
+                    .cmp(&b_number.len())
+                    .then_with(|| a_number.cmp(b_number))
+                    .then_with(|| a_digits.len().cmp(&b_digits.len()));
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+            }
+            (Some(a_char), Some(b_char)) => {
+                let ordering = a_char.cmp(&b_char);
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+                a.next();
+                b.next();
+            }
+        }
+    }
+}
+
+fn take_digits(chars: &mut std::iter::Peekable<std::str::Chars>) -> String {
+    let mut digits = String::new();
+    while let Some(digit) = chars.next_if(|each| each.is_ascii_digit()) {
+        digits.push(digit);
+    }
+    digits
+}
@@ -0,0 +1,65 @@
+use crate::{FileMatcherError, ManyEntries, OneEntry, Result};
+use std::io::ErrorKind;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+/// How often the folder is searched again while waiting
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Waiting for entries produced by another process. The folder is searched repeatedly
+/// until the search succeeds or the timeout elapses
+impl OneEntry {
+    /// Wait until there exists exactly one entry of the specified type and name.
+    /// Returns [`FileMatcherError::Timeout`] with the error of the last search
+    /// if the entry did not appear within the timeout
+    pub fn wait_for(&self, timeout: Duration) -> Result<PathBuf> {
+        wait_for(timeout, || self.find())
+    }
+}
+
+impl ManyEntries {
+    /// Wait until the amount of found entries is expected, see [`ManyEntries::count`].
+    /// Returns [`FileMatcherError::Timeout`] with the error of the last search
+    /// if the entries did not appear within the timeout
+    pub fn wait_for(&self, timeout: Duration) -> Result<Vec<PathBuf>> {
+        wait_for(timeout, || self.find())
+    }
+
+    /// Wait until at least a given amount of entries exists
+    pub fn wait_for_at_least(&self, min: usize, timeout: Duration) -> Result<Vec<PathBuf>> {
+        self.clone().at_least(min).wait_for(timeout)
+    }
+}
+
+/// A timeout too long to be represented as an instant, such as [`Duration::MAX`], never elapses
+fn wait_for<T>(timeout: Duration, find: impl Fn() -> Result<T>) -> Result<T> {
+    let deadline = Instant::now().checked_add(timeout);
+    loop {
+        let error = match find() {
+            Ok(found) => return Ok(found),
+            Err(error) if is_pending(&error) => error,
+            Err(error) => return Err(error),
+        };
+
+        let remaining = match deadline {
+            Some(deadline) => deadline.saturating_duration_since(Instant::now()),
+            None => POLL_INTERVAL,
+        };
+        if remaining.is_zero() {
+            return FileMatcherError::Timeout(timeout, Box::new(error)).into();
+        }
+        std::thread::sleep(POLL_INTERVAL.min(remaining));
+    }
+}
+
+/// Return true if the search may succeed later, for example once the entry is created.
+/// The searched folder itself may not exist yet
+fn is_pending(error: &FileMatcherError) -> bool {
+    match error {
+        FileMatcherError::NotExists(_)
+        | FileMatcherError::TooMany(_)
+        | FileMatcherError::WrongCount(_, _) => true,
+        FileMatcherError::IoError(error) => error.kind() == ErrorKind::NotFound,
+        _ => false,
+    }
+}
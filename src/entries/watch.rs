@@ -0,0 +1,255 @@
+#[cfg(not(feature = "watch"))]
+compile_error!("Please select a watch feature to build with watch support");
+
+use crate::finders::name_matcher::EntryNameMatcher;
+use crate::utils::is_readable_entry;
+use crate::{EntryType, ManyEntries, OneEntry, Result, StdFileSystem};
+use notify::event::{CreateKind, EventKind, ModifyKind, RemoveKind, RenameMode};
+use notify::{Event, RecommendedWatcher, RecursiveMode, Watcher};
+use std::io::{Error, ErrorKind};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{channel, Receiver, RecvTimeoutError, TryRecvError};
+use std::time::Duration;
+
+/// A change of a watched entry
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum WatchEvent {
+    /// The entry was created or renamed to a matching name
+    Created(PathBuf),
+    /// The content or the metadata of the entry changed
+    Modified(PathBuf),
+    /// The entry was removed or renamed to another name
+    Removed(PathBuf),
+}
+
+impl WatchEvent {
+    /// The path of the changed entry within the watched folder
+    pub fn path(&self) -> &Path {
+        match self {
+            WatchEvent::Created(path) | WatchEvent::Modified(path) | WatchEvent::Removed(path) => {
+                path
+            }
+        }
+    }
+}
+
+/// Receives the changes of the entries matching a name and a type within a folder,
+/// as reported by the operating system (inotify on Linux). Each write may be reported
+/// as a separate modification. The folder is watched until the watcher is dropped
+#[derive(Debug)]
+pub struct EntriesWatcher {
+    events: Receiver<Result<WatchEvent>>,
+    _watcher: RecommendedWatcher,
+}
+
+impl EntriesWatcher {
+    /// Wait for the next change
+    pub fn recv(&self) -> Result<WatchEvent> {
+        match self.events.recv() {
+            Ok(event) => event,
+            Err(_) => Err(Error::from(ErrorKind::BrokenPipe).into()),
+        }
+    }
+
+    /// Wait for the next change for at most a given time, returns None on timeout
+    pub fn recv_timeout(&self, timeout: Duration) -> Result<Option<WatchEvent>> {
+        match self.events.recv_timeout(timeout) {
+            Ok(event) => event.map(Some),
+            Err(RecvTimeoutError::Timeout) => Ok(None),
+            Err(RecvTimeoutError::Disconnected) => Err(Error::from(ErrorKind::BrokenPipe).into()),
+        }
+    }
+
+    /// Return the next change if there is one, without waiting
+    pub fn try_recv(&self) -> Result<Option<WatchEvent>> {
+        match self.events.try_recv() {
+            Ok(event) => event.map(Some),
+            Err(TryRecvError::Empty) => Ok(None),
+            Err(TryRecvError::Disconnected) => Err(Error::from(ErrorKind::BrokenPipe).into()),
+        }
+    }
+}
+
+/// Waits for the changes one by one, never ends while the folder is watched
+impl Iterator for EntriesWatcher {
+    type Item = Result<WatchEvent>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.events.recv().ok()
+    }
+}
+
+/// Watching the changes of entries on disk, the file system of the entries is not used
+impl OneEntry {
+    /// Watch the folder for the changes of the entries matching the name and the type.
+    /// The folder must exist
+    pub fn watch(&self) -> Result<EntriesWatcher> {
+        watch(
+            self.directory(),
+            false,
+            self.entry_type(),
+            self.entry_name(),
+        )
+    }
+}
+
+impl ManyEntries {
+    /// Watch the folder, and all nested folders when searched recursively,
+    /// for the changes of the entries matching the name and the type. The folder must exist
+    pub fn watch(&self) -> Result<EntriesWatcher> {
+        watch(
+            self.directory(),
+            self.is_recursive(),
+            self.entry_type(),
+            self.entry_name(),
+        )
+    }
+}
+
+fn watch(
+    directory: &Path,
+    recursive: bool,
+    entry_type: &EntryType,
+    entry_name: &crate::EntryName,
+) -> Result<EntriesWatcher> {
+    let filter = EventFilter {
+        directory: directory.to_path_buf(),
+        watched: watched_forms(directory),
+        recursive,
+        entry_type: entry_type.clone(),
+        matcher: EntryNameMatcher::new(entry_name)?,
+    };
+
+    let (sender, events) = channel();
+    let mut watcher =
+        notify::recommended_watcher(move |event: notify::Result<Event>| match event {
+            Ok(event) => {
+                for each in filter.watch_events(event) {
+                    let _ = sender.send(Ok(each));
+                }
+            }
+            Err(error) => {
+                let _ = sender.send(Err(error.into()));
+            }
+        })?;
+
+    let mode = if recursive {
+        RecursiveMode::Recursive
+    } else {
+        RecursiveMode::NonRecursive
+    };
+    watcher.watch(directory, mode)?;
+
+    Ok(EntriesWatcher {
+        events,
+        _watcher: watcher,
+    })
+}
+
+/// The forms in which the operating system may report the paths within a folder
+fn watched_forms(directory: &Path) -> Vec<PathBuf> {
+    let mut forms = vec![directory.to_path_buf()];
+    if let Ok(absolute) = std::path::absolute(directory) {
+        forms.push(absolute);
+    }
+    if let Ok(canonical) = directory.canonicalize() {
+        forms.push(canonical);
+    }
+    forms
+}
+
+/// Translates the events of the operating system to the changes of the matching entries
+struct EventFilter {
+    directory: PathBuf,
+    watched: Vec<PathBuf>,
+    recursive: bool,
+    entry_type: EntryType,
+    matcher: EntryNameMatcher,
+}
+
+impl EventFilter {
+    fn watch_events(&self, event: Event) -> Vec<WatchEvent> {
+        let (path, kind) = match (event.kind, event.paths.into_iter().next()) {
+            (_, None) => return vec![],
+            (EventKind::Create(CreateKind::File), Some(path)) => (path, Kind::CreatedFile),
+            (EventKind::Create(CreateKind::Folder), Some(path)) => (path, Kind::CreatedFolder),
+            (EventKind::Create(_), Some(path)) => (path, Kind::Created),
+            (EventKind::Remove(RemoveKind::File), Some(path)) => (path, Kind::RemovedFile),
+            (EventKind::Remove(RemoveKind::Folder), Some(path)) => (path, Kind::RemovedFolder),
+            (EventKind::Remove(_), Some(path)) => (path, Kind::Removed),
+            (EventKind::Modify(ModifyKind::Name(RenameMode::From)), Some(path)) => {
+                (path, Kind::Removed)
+            }
+            (EventKind::Modify(ModifyKind::Name(RenameMode::To)), Some(path)) => {
+                (path, Kind::Created)
+            }
+            // a rename is also reported by the events of its both ends
+            (EventKind::Modify(ModifyKind::Name(RenameMode::Both)), Some(_)) => return vec![],
+            (EventKind::Modify(ModifyKind::Name(_)), Some(path)) => {
+                if path.symlink_metadata().is_ok() {
+                    (path, Kind::Created)
+                } else {
+                    (path, Kind::Removed)
+                }
+            }
+            (EventKind::Modify(_), Some(path)) => (path, Kind::Modified),
+            _ => return vec![],
+        };
+        self.watch_events_of(path, kind)
+    }
+
+    fn watch_events_of(&self, path: PathBuf, kind: Kind) -> Vec<WatchEvent> {
+        let path = match self.watched_path(&path) {
+            Some(path) if self.matcher.matches_path(&self.directory, &path) => path,
+            _ => return vec![],
+        };
+
+        let is_of_type = match (&kind, &self.entry_type) {
+            (_, EntryType::Any) => true,
+            (Kind::Created | Kind::Modified, entry_type) => {
+                is_readable_entry(&StdFileSystem, entry_type, &path)
+            }
+            // the entry may already be replaced by another one when the event is received
+            (Kind::CreatedFile | Kind::RemovedFile, entry_type) => entry_type == &EntryType::File,
+            (Kind::CreatedFolder | Kind::RemovedFolder, entry_type) => {
+                entry_type == &EntryType::Folder
+            }
+            // the type of a removed entry is unknown
+            (Kind::Removed, _) => true,
+        };
+        if !is_of_type {
+            return vec![];
+        }
+
+        vec![match kind {
+            Kind::Created | Kind::CreatedFile | Kind::CreatedFolder => WatchEvent::Created(path),
+            Kind::Modified => WatchEvent::Modified(path),
+            Kind::Removed | Kind::RemovedFile | Kind::RemovedFolder => WatchEvent::Removed(path),
+        }]
+    }
+
+    /// Translate a reported path to a path within the watched folder as it was given,
+    /// returns None if the path is not an entry of the folder
+    fn watched_path(&self, path: &Path) -> Option<PathBuf> {
+        let relative = self
+            .watched
+            .iter()
+            .find_map(|each| path.strip_prefix(each).ok())?;
+
+        let depth = relative.components().count();
+        if depth == 0 || (depth > 1 && !self.recursive) {
+            return None;
+        }
+        Some(self.directory.join(relative))
+    }
+}
+
+enum Kind {
+    Created,
+    CreatedFile,
+    CreatedFolder,
+    Modified,
+    Removed,
+    RemovedFile,
+    RemovedFolder,
+}
@@ -1,8 +1,9 @@
-use crate::OneEntry;
+use crate::{ManyEntries, OneEntry};
 use std::error;
 use std::ffi::OsString;
 use std::fmt;
 use std::path::PathBuf;
+use std::time::Duration;
 
 pub type Result<T> = core::result::Result<T, FileMatcherError>;
 
@@ -10,13 +11,24 @@ pub type Result<T> = core::result::Result<T, FileMatcherError>;
 pub enum FileMatcherError {
     TooMany(OneEntry),
     NotExists(OneEntry),
+    WrongCount(ManyEntries, Vec<PathBuf>),
     NotReadable(PathBuf),
+    RemovingSearchFolder(PathBuf),
+    /// Entries were not found within the timeout, with the error of the last search
+    Timeout(Duration, Box<FileMatcherError>),
+    /// The checksum of a copy (second) differs from the copied file (first)
+    #[cfg(any(feature = "sha256", feature = "blake3"))]
+    ChecksumMismatch(PathBuf, PathBuf),
     InvalidUnicode(OsString),
     IoError(std::io::Error),
     #[cfg(feature = "fs_extra")]
     FsExtraError(fs_extra::error::Error),
     #[cfg(feature = "regex")]
     RegexError(regex::Error),
+    #[cfg(feature = "zip")]
+    ZipError(zip::result::ZipError),
+    #[cfg(feature = "watch")]
+    WatchError(notify::Error),
 }
 
 impl fmt::Display for FileMatcherError {
@@ -40,6 +52,18 @@ impl fmt::Display for FileMatcherError {
                     entry.directory()
                 )
             }
+            FileMatcherError::WrongCount(entries, found) => {
+                write!(
+                    f,
+                    "Expected {} {:?} named {:?} in {:?}, but found {}: {:?}",
+                    entries.count(),
+                    entries.entry_type(),
+                    entries.entry_name(),
+                    entries.directory(),
+                    found.len(),
+                    found
+                )
+            }
             FileMatcherError::IoError(error) => {
                 write!(f, "Failed to perform IO operation {:?}", error)
             }
@@ -51,9 +75,35 @@ impl fmt::Display for FileMatcherError {
             FileMatcherError::RegexError(error) => {
                 write!(f, "Failed to create regex {:?}", error)
             }
+            #[cfg(feature = "zip")]
+            FileMatcherError::ZipError(error) => {
+                write!(f, "Failed to read zip archive {:?}", error)
+            }
+            #[cfg(feature = "watch")]
+            FileMatcherError::WatchError(error) => {
+                write!(f, "Failed to watch {:?}", error)
+            }
+            FileMatcherError::Timeout(timeout, last) => {
+                write!(f, "Timed out after {:?}: {}", timeout, last)
+            }
             FileMatcherError::NotReadable(path) => {
                 write!(f, "Failed to read {:?}", path)
             }
+            FileMatcherError::RemovingSearchFolder(path) => {
+                write!(
+                    f,
+                    "Refusing to remove {:?} as it is or contains the search folder",
+                    path
+                )
+            }
+            #[cfg(any(feature = "sha256", feature = "blake3"))]
+            FileMatcherError::ChecksumMismatch(file, copy) => {
+                write!(
+                    f,
+                    "Checksum of the copy {:?} does not match {:?}",
+                    copy, file
+                )
+            }
             FileMatcherError::InvalidUnicode(file_name) => {
                 write!(f, "Failed to convert {:?} to Unicode", file_name)
             }
@@ -65,8 +115,13 @@ impl error::Error for FileMatcherError {
     fn source(&self) -> Option<&(dyn error::Error + 'static)> {
         match *self {
             FileMatcherError::IoError(ref e) => Some(e),
+            FileMatcherError::Timeout(_, ref e) => Some(e.as_ref()),
             #[cfg(feature = "regex")]
             FileMatcherError::RegexError(ref e) => Some(e),
+            #[cfg(feature = "zip")]
+            FileMatcherError::ZipError(ref e) => Some(e),
+            #[cfg(feature = "watch")]
+            FileMatcherError::WatchError(ref e) => Some(e),
             _ => None,
         }
     }
@@ -78,6 +133,20 @@ impl From<regex::Error> for FileMatcherError {
     }
 }
 
+#[cfg(feature = "zip")]
+impl From<zip::result::ZipError> for FileMatcherError {
+    fn from(err: zip::result::ZipError) -> FileMatcherError {
+        FileMatcherError::ZipError(err)
+    }
+}
+
+#[cfg(feature = "watch")]
+impl From<notify::Error> for FileMatcherError {
+    fn from(err: notify::Error) -> FileMatcherError {
+        FileMatcherError::WatchError(err)
+    }
+}
+
 impl From<std::io::Error> for FileMatcherError {
     fn from(err: std::io::Error) -> FileMatcherError {
         FileMatcherError::IoError(err)
@@ -0,0 +1,119 @@
+use crate::EntryMetadata;
+use std::collections::BTreeMap;
+use std::io::{Error, ErrorKind};
+use std::path::{Component, Path, PathBuf};
+
+/// An index of the files and folders of an archive by their normalized path within the archive.
+/// The root of the archive is an empty path, folders missing in the archive
+/// are created for every file
+#[derive(Debug, Clone)]
+pub(crate) struct ArchiveEntries<T> {
+    entries: BTreeMap<PathBuf, ArchiveEntry<T>>,
+}
+
+#[derive(Debug, Clone)]
+struct ArchiveEntry<T> {
+    metadata: EntryMetadata,
+    content: Option<T>,
+}
+
+impl<T> ArchiveEntries<T> {
+    pub fn new() -> Self {
+        Self {
+            entries: BTreeMap::new(),
+        }
+    }
+
+    pub fn add_file(&mut self, path: impl AsRef<Path>, metadata: EntryMetadata, content: T) {
+        let path = normalize(path);
+        self.add_parents(&path);
+        self.entries.insert(
+            path,
+            ArchiveEntry {
+                metadata,
+                content: Some(content),
+            },
+        );
+    }
+
+    pub fn add_folder(&mut self, path: impl AsRef<Path>, metadata: EntryMetadata) {
+        let path = normalize(path);
+        self.add_parents(&path);
+        self.entries.insert(
+            path,
+            ArchiveEntry {
+                metadata,
+                content: None,
+            },
+        );
+    }
+
+    fn add_parents(&mut self, path: &Path) {
+        for each_parent in path.ancestors().skip(1) {
+            if each_parent.parent().is_none() {
+                continue;
+            }
+            self.entries
+                .entry(each_parent.to_path_buf())
+                .or_insert_with(|| ArchiveEntry {
+                    metadata: EntryMetadata::folder(None),
+                    content: None,
+                });
+        }
+    }
+
+    pub fn metadata(&self, path: impl AsRef<Path>) -> std::io::Result<EntryMetadata> {
+        let path = normalize(path);
+        if path.parent().is_none() {
+            return Ok(EntryMetadata::folder(None));
+        }
+        self.entries
+            .get(&path)
+            .map(|entry| entry.metadata.clone())
+            .ok_or_else(|| Error::from(ErrorKind::NotFound))
+    }
+
+    /// Return the paths of the entries within a folder joined with a given folder path
+    pub fn read_dir(&self, path: impl AsRef<Path>) -> std::io::Result<Vec<PathBuf>> {
+        let path = path.as_ref();
+        if !self.metadata(path)?.is_folder() {
+            return Err(Error::from(ErrorKind::NotFound));
+        }
+        let normalized = normalize(path);
+        Ok(self
+            .entries
+            .keys()
+            .filter(|each| each.parent() == Some(normalized.as_path()))
+            .filter_map(|each| each.file_name())
+            .map(|each| path.join(each))
+            .collect())
+    }
+
+    /// Return the content of a file
+    pub fn content(&self, path: impl AsRef<Path>) -> std::io::Result<&T> {
+        self.entries
+            .get(&normalize(path))
+            .and_then(|entry| entry.content.as_ref())
+            .ok_or_else(|| Error::from(ErrorKind::NotFound))
+    }
+}
+
+/// Return a path relative to the root of an archive, resolving the parent folder components
+/// and ignoring the root and current folder components. The parent of the root is the root
+pub(crate) fn normalize(path: impl AsRef<Path>) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.as_ref().components() {
+        match component {
+            Component::Normal(name) => normalized.push(name),
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            _ => {}
+        }
+    }
+    normalized
+}
+
+pub(crate) fn read_only() -> Error {
+    Error::new(ErrorKind::Unsupported, "Archives are read-only")
+}
@@ -0,0 +1,301 @@
+use crate::{EntryMetadata, FileSystem};
+use std::collections::BTreeMap;
+use std::io::{Cursor, Error, ErrorKind, Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::SystemTime;
+
+/// A [`FileSystem`] that keeps files and folders in memory.
+/// Clones share the same entries, which allows to inspect the result of an operation.
+/// A path without a parent, such as an empty path, is always an existing folder
+/// ```
+/// use file_matcher::{FileNamed, MemoryFileSystem};
+///
+/// # use file_matcher::Result;
+/// # fn main() -> Result<()> {
+///     let file_system = MemoryFileSystem::new().with_file("assets/cat.txt", "meow");
+///
+///     let bytes = FileNamed::wildmatch("cat*")
+///         .within("assets")
+///         .with_file_system(file_system)
+///         .as_bytes()?;
+///
+///     assert_eq!(bytes, b"meow");
+/// #    Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct MemoryFileSystem {
+    entries: Arc<Mutex<BTreeMap<PathBuf, MemoryEntry>>>,
+}
+
+#[derive(Debug, Clone)]
+enum MemoryEntry {
+    File {
+        contents: Vec<u8>,
+        modified: SystemTime,
+    },
+    Folder {
+        modified: SystemTime,
+    },
+}
+
+impl MemoryFileSystem {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a file with the given contents creating all of its missing parent folders
+    pub fn with_file(self, path: impl AsRef<Path>, contents: impl Into<Vec<u8>>) -> Self {
+        self.add_file(path, contents);
+        self
+    }
+
+    /// Add a folder and all of its missing parents
+    pub fn with_folder(self, path: impl AsRef<Path>) -> Self {
+        self.add_folder(path);
+        self
+    }
+
+    /// Add a file with the given contents creating all of its missing parent folders,
+    /// replaces an existing entry with the same path
+    pub fn add_file(&self, path: impl AsRef<Path>, contents: impl Into<Vec<u8>>) {
+        let path = path.as_ref();
+        if let Some(parent) = path.parent() {
+            self.add_folder(parent);
+        }
+        self.entries().insert(
+            path.to_path_buf(),
+            MemoryEntry::File {
+                contents: contents.into(),
+                modified: SystemTime::now(),
+            },
+        );
+    }
+
+    /// Add a folder and all of its missing parents
+    pub fn add_folder(&self, path: impl AsRef<Path>) {
+        let mut entries = self.entries();
+        for each_folder in path.as_ref().ancestors() {
+            if each_folder.parent().is_none() || entries.contains_key(each_folder) {
+                continue;
+            }
+            entries.insert(
+                each_folder.to_path_buf(),
+                MemoryEntry::Folder {
+                    modified: SystemTime::now(),
+                },
+            );
+        }
+    }
+
+    /// Return the contents of a file
+    pub fn read(&self, path: impl AsRef<Path>) -> std::io::Result<Vec<u8>> {
+        match self.entries().get(path.as_ref()) {
+            Some(MemoryEntry::File { contents, .. }) => Ok(contents.clone()),
+            Some(MemoryEntry::Folder { .. }) => Err(not_a_file()),
+            None => Err(not_found()),
+        }
+    }
+
+    /// Return true if there is a file or a folder with a given path
+    pub fn contains(&self, path: impl AsRef<Path>) -> bool {
+        let path = path.as_ref();
+        path.parent().is_none() || self.entries().contains_key(path)
+    }
+
+    fn entries(&self) -> MutexGuard<'_, BTreeMap<PathBuf, MemoryEntry>> {
+        self.entries.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn is_folder(entries: &BTreeMap<PathBuf, MemoryEntry>, path: &Path) -> bool {
+        path.parent().is_none() || matches!(entries.get(path), Some(MemoryEntry::Folder { .. }))
+    }
+}
+
+impl FileSystem for MemoryFileSystem {
+    fn metadata(&self, path: &Path) -> std::io::Result<EntryMetadata> {
+        if path.parent().is_none() {
+            return Ok(EntryMetadata::folder(None));
+        }
+        match self.entries().get(path) {
+            Some(MemoryEntry::File { contents, modified }) => {
+                Ok(EntryMetadata::file(contents.len() as u64, Some(*modified)))
+            }
+            Some(MemoryEntry::Folder { modified }) => Ok(EntryMetadata::folder(Some(*modified))),
+            None => Err(not_found()),
+        }
+    }
+
+    fn read_dir(
+        &self,
+        path: &Path,
+    ) -> std::io::Result<Box<dyn Iterator<Item = std::io::Result<PathBuf>>>> {
+        let entries = self.entries();
+        if !Self::is_folder(&entries, path) {
+            return Err(not_found());
+        }
+        let children = entries
+            .keys()
+            .filter(|each| each.parent() == Some(path))
+            .cloned()
+            .map(Ok)
+            .collect::<Vec<std::io::Result<PathBuf>>>();
+        Ok(Box::new(children.into_iter()))
+    }
+
+    fn open(&self, path: &Path) -> std::io::Result<Box<dyn Read>> {
+        Ok(Box::new(Cursor::new(self.read(path)?)))
+    }
+
+    fn create(&self, path: &Path) -> std::io::Result<Box<dyn Write>> {
+        let mut entries = self.entries();
+        if !path
+            .parent()
+            .is_some_and(|parent| Self::is_folder(&entries, parent))
+        {
+            return Err(not_found());
+        }
+        if let Some(MemoryEntry::Folder { .. }) = entries.get(path) {
+            return Err(not_a_file());
+        }
+        entries.insert(
+            path.to_path_buf(),
+            MemoryEntry::File {
+                contents: vec![],
+                modified: SystemTime::now(),
+            },
+        );
+        Ok(Box::new(MemoryFileWriter {
+            file_system: self.clone(),
+            path: path.to_path_buf(),
+        }))
+    }
+
+    fn create_dir_all(&self, path: &Path) -> std::io::Result<()> {
+        if path
+            .ancestors()
+            .any(|each| matches!(self.entries().get(each), Some(MemoryEntry::File { .. })))
+        {
+            return Err(Error::from(ErrorKind::AlreadyExists));
+        }
+        self.add_folder(path);
+        Ok(())
+    }
+
+    fn remove_file(&self, path: &Path) -> std::io::Result<()> {
+        let mut entries = self.entries();
+        match entries.get(path) {
+            Some(MemoryEntry::File { .. }) => {
+                entries.remove(path);
+                Ok(())
+            }
+            Some(MemoryEntry::Folder { .. }) => Err(not_a_file()),
+            None => Err(not_found()),
+        }
+    }
+
+    fn remove_dir_all(&self, path: &Path) -> std::io::Result<()> {
+        let mut entries = self.entries();
+        match entries.get(path) {
+            Some(MemoryEntry::Folder { .. }) => {
+                entries.retain(|each, _| !each.starts_with(path));
+                Ok(())
+            }
+            Some(MemoryEntry::File { .. }) => Err(Error::other("Not a folder")),
+            None => Err(not_found()),
+        }
+    }
+
+    /// A folder can only be renamed to a path that does not exist
+    fn rename(&self, from: &Path, to: &Path) -> std::io::Result<()> {
+        let mut entries = self.entries();
+        if !to
+            .parent()
+            .is_some_and(|parent| Self::is_folder(&entries, parent))
+        {
+            return Err(not_found());
+        }
+        match (entries.get(from), entries.get(to)) {
+            (None, _) => Err(not_found()),
+            (Some(MemoryEntry::File { .. }), Some(MemoryEntry::Folder { .. })) => Err(not_a_file()),
+            (Some(MemoryEntry::Folder { .. }), Some(_)) => {
+                Err(Error::from(ErrorKind::AlreadyExists))
+            }
+            (Some(_), _) => {
+                let renamed = entries
+                    .keys()
+                    .filter(|each| each.starts_with(from))
+                    .cloned()
+                    .collect::<Vec<PathBuf>>();
+                for each in renamed {
+                    if let (Some(entry), Ok(relative)) =
+                        (entries.remove(&each), each.strip_prefix(from))
+                    {
+                        let renamed = if relative.as_os_str().is_empty() {
+                            to.to_path_buf()
+                        } else {
+                            to.join(relative)
+                        };
+                        entries.insert(renamed, entry);
+                    }
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// Only the modification time is kept in memory, the access time is ignored
+    fn set_times(
+        &self,
+        path: &Path,
+        _accessed: Option<SystemTime>,
+        modified: Option<SystemTime>,
+    ) -> std::io::Result<()> {
+        match self.entries().get_mut(path) {
+            Some(MemoryEntry::File {
+                modified: entry_modified,
+                ..
+            })
+            | Some(MemoryEntry::Folder {
+                modified: entry_modified,
+            }) => {
+                if let Some(modified) = modified {
+                    *entry_modified = modified;
+                }
+                Ok(())
+            }
+            None => Err(not_found()),
+        }
+    }
+}
+
+struct MemoryFileWriter {
+    file_system: MemoryFileSystem,
+    path: PathBuf,
+}
+
+impl Write for MemoryFileWriter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        match self.file_system.entries().get_mut(&self.path) {
+            Some(MemoryEntry::File { contents, modified }) => {
+                contents.extend_from_slice(buf);
+                *modified = SystemTime::now();
+                Ok(buf.len())
+            }
+            _ => Err(not_found()),
+        }
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+fn not_found() -> Error {
+    Error::from(ErrorKind::NotFound)
+}
+
+fn not_a_file() -> Error {
+    Error::other("Not a file")
+}
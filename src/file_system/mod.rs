@@ -0,0 +1,343 @@
+#[cfg(any(feature = "zip", feature = "tar"))]
+mod archive_entries;
+mod memory_file_system;
+mod std_file_system;
+#[cfg(feature = "tar")]
+mod tar_file_system;
+#[cfg(feature = "zip")]
+mod zip_file_system;
+
+pub use memory_file_system::MemoryFileSystem;
+pub use std_file_system::StdFileSystem;
+#[cfg(feature = "tar")]
+pub use tar_file_system::{TarCompression, TarFileSystem};
+#[cfg(feature = "zip")]
+pub(crate) use zip_file_system::zip_date_time;
+#[cfg(feature = "zip")]
+pub use zip_file_system::ZipFileSystem;
+
+use crate::Result;
+use std::ffi::{OsStr, OsString};
+use std::fmt::Debug;
+use std::io::{Error, ErrorKind, Read, Write};
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+/// Abstracts the file system operations used to find, read, copy and move entries.
+/// [`StdFileSystem`] is used by default, [`MemoryFileSystem`] allows to write hermetic tests
+pub trait FileSystem: Debug + Send + Sync {
+    /// Return the metadata of an entry without following symbolic links
+    fn metadata(&self, path: &Path) -> std::io::Result<EntryMetadata>;
+
+    /// Return true if the path is a folder or a symbolic link to a folder.
+    /// Used to decide if a copied, moved or linked entry goes inside of a destination
+    fn resolves_to_folder(&self, path: &Path) -> bool {
+        self.metadata(path)
+            .map(|metadata| metadata.is_folder())
+            .unwrap_or(false)
+    }
+
+    /// Return an absolute path of an entry, resolving symbolic links where the file system
+    /// supports them. Used to compare the paths of entries regardless of how they are spelled
+    fn absolute_path(&self, path: &Path) -> PathBuf {
+        std::path::absolute(path).unwrap_or_else(|_| path.to_path_buf())
+    }
+
+    /// Return the paths of the entries within a folder
+    fn read_dir(
+        &self,
+        path: &Path,
+    ) -> std::io::Result<Box<dyn Iterator<Item = std::io::Result<PathBuf>>>>;
+
+    /// Open a file for reading
+    fn open(&self, path: &Path) -> std::io::Result<Box<dyn Read>>;
+
+    /// Create a file for writing, truncating it if it already exists
+    fn create(&self, path: &Path) -> std::io::Result<Box<dyn Write>>;
+
+    /// Create a folder and all of its missing parents
+    fn create_dir_all(&self, path: &Path) -> std::io::Result<()>;
+
+    fn remove_file(&self, path: &Path) -> std::io::Result<()>;
+
+    /// Remove a folder with all its content
+    fn remove_dir_all(&self, path: &Path) -> std::io::Result<()>;
+
+    /// Rename a file or a folder replacing the destination file if it exists.
+    /// Not supported by default
+    fn rename(&self, from: &Path, to: &Path) -> std::io::Result<()> {
+        let _ = (from, to);
+        Err(Error::from(ErrorKind::Unsupported))
+    }
+
+    /// Create a symbolic link at `link` pointing to `target`,
+    /// a relative target is resolved against the folder of the link.
+    /// Not supported by default
+    fn symlink(&self, target: &Path, link: &Path) -> std::io::Result<()> {
+        let _ = (target, link);
+        Err(Error::from(ErrorKind::Unsupported))
+    }
+
+    /// Create a hard link at `link` to the `original` file. Not supported by default
+    fn hard_link(&self, original: &Path, link: &Path) -> std::io::Result<()> {
+        let _ = (original, link);
+        Err(Error::from(ErrorKind::Unsupported))
+    }
+
+    /// Set the access and modification times of an entry, `None` keeps the current time.
+    /// Not supported by default
+    fn set_times(
+        &self,
+        path: &Path,
+        accessed: Option<SystemTime>,
+        modified: Option<SystemTime>,
+    ) -> std::io::Result<()> {
+        let _ = (path, accessed, modified);
+        Err(Error::from(ErrorKind::Unsupported))
+    }
+
+    /// Set the Unix permission bits of an entry. Not supported by default
+    fn set_mode(&self, path: &Path, mode: u32) -> std::io::Result<()> {
+        let _ = (path, mode);
+        Err(Error::from(ErrorKind::Unsupported))
+    }
+
+    /// Set the user and group ids owning an entry. Not supported by default
+    fn set_owner(&self, path: &Path, uid: u32, gid: u32) -> std::io::Result<()> {
+        let _ = (path, uid, gid);
+        Err(Error::from(ErrorKind::Unsupported))
+    }
+
+    /// Return the names and values of the extended attributes of an entry,
+    /// a file system without extended attributes has none
+    fn xattrs(&self, path: &Path) -> std::io::Result<Vec<(OsString, Vec<u8>)>> {
+        let _ = path;
+        Ok(vec![])
+    }
+
+    /// Set an extended attribute of an entry. Not supported by default
+    fn set_xattr(&self, path: &Path, name: &OsStr, value: &[u8]) -> std::io::Result<()> {
+        let _ = (path, name, value);
+        Err(Error::from(ErrorKind::Unsupported))
+    }
+
+    /// Copy the content of a file overwriting the destination,
+    /// returns the amount of copied bytes
+    fn copy_file(&self, from: &Path, to: &Path) -> std::io::Result<u64> {
+        let mut reader = self.open(from)?;
+        let mut writer = self.create(to)?;
+        let copied = std::io::copy(&mut reader, &mut writer)?;
+        writer.flush()?;
+        Ok(copied)
+    }
+
+    /// Recursively copy a folder. If the destination exists the folder is copied inside of it,
+    /// otherwise the destination is created with the content of the folder.
+    /// Fails if any of the copied files already exists
+    fn copy_folder(&self, from: &Path, to: &Path) -> Result<()> {
+        copy_folder_with(self, from, self, to, &mut |from, to| {
+            self.copy_file(from, to)
+        })
+    }
+}
+
+/// Copy a file from one file system to another overwriting the destination,
+/// returns the amount of copied bytes
+#[cfg(feature = "copier")]
+pub(crate) fn copy_file_between(
+    from_file_system: &dyn FileSystem,
+    from: &Path,
+    to_file_system: &dyn FileSystem,
+    to: &Path,
+) -> std::io::Result<u64> {
+    let mut reader = from_file_system.open(from)?;
+    let mut writer = to_file_system.create(to)?;
+    let copied = std::io::copy(&mut reader, &mut writer)?;
+    writer.flush()?;
+    Ok(copied)
+}
+
+/// Recursively copy a folder from one file system to another
+/// with the same semantics as [`FileSystem::copy_folder`]
+#[cfg(feature = "copier")]
+pub(crate) fn copy_folder_between(
+    from_file_system: &dyn FileSystem,
+    from: &Path,
+    to_file_system: &dyn FileSystem,
+    to: &Path,
+) -> Result<()> {
+    copy_folder_with(
+        from_file_system,
+        from,
+        to_file_system,
+        to,
+        &mut |from, to| copy_file_between(from_file_system, from, to_file_system, to),
+    )
+}
+
+/// Recursively copy a folder using a given function to copy each file
+pub(crate) fn copy_folder_with<F: FileSystem + ?Sized, T: FileSystem + ?Sized>(
+    from_file_system: &F,
+    from: &Path,
+    to_file_system: &T,
+    to: &Path,
+    copy_file: &mut dyn FnMut(&Path, &Path) -> std::io::Result<u64>,
+) -> Result<()> {
+    let destination = if to_file_system.metadata(to).is_ok() {
+        match from.file_name() {
+            Some(folder_name) => to.join(folder_name),
+            None => return Err(Error::from(ErrorKind::InvalidInput).into()),
+        }
+    } else {
+        to.to_path_buf()
+    };
+    copy_folder_content(
+        from_file_system,
+        from,
+        to_file_system,
+        &destination,
+        copy_file,
+    )
+}
+
+fn copy_folder_content<F: FileSystem + ?Sized, T: FileSystem + ?Sized>(
+    from_file_system: &F,
+    from: &Path,
+    to_file_system: &T,
+    to: &Path,
+    copy_file: &mut dyn FnMut(&Path, &Path) -> std::io::Result<u64>,
+) -> Result<()> {
+    to_file_system.create_dir_all(to)?;
+    for each_entry in from_file_system.read_dir(from)? {
+        let each_entry = each_entry?;
+        let each_name = match each_entry.file_name() {
+            Some(name) => name,
+            None => continue,
+        };
+        let metadata = from_file_system.metadata(&each_entry)?;
+        let each_destination = to.join(each_name);
+
+        if metadata.is_folder() {
+            copy_folder_content(
+                from_file_system,
+                &each_entry,
+                to_file_system,
+                &each_destination,
+                copy_file,
+            )?;
+        } else if metadata.is_file() {
+            if to_file_system.metadata(&each_destination).is_ok() {
+                return Err(Error::from(ErrorKind::AlreadyExists).into());
+            }
+            copy_file(&each_entry, &each_destination)?;
+        }
+    }
+    Ok(())
+}
+
+/// Describes an entry of a [`FileSystem`]
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct EntryMetadata {
+    kind: EntryKind,
+    len: u64,
+    modified: Option<SystemTime>,
+    accessed: Option<SystemTime>,
+    mode: Option<u32>,
+    owner: Option<(u32, u32)>,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+enum EntryKind {
+    File,
+    Folder,
+    Other,
+}
+
+impl EntryMetadata {
+    pub fn file(len: u64, modified: Option<SystemTime>) -> Self {
+        Self {
+            kind: EntryKind::File,
+            len,
+            modified,
+            accessed: None,
+            mode: None,
+            owner: None,
+        }
+    }
+
+    pub fn folder(modified: Option<SystemTime>) -> Self {
+        Self {
+            kind: EntryKind::Folder,
+            len: 0,
+            modified,
+            accessed: None,
+            mode: None,
+            owner: None,
+        }
+    }
+
+    /// An entry that is neither a file nor a folder, for example a symbolic link
+    pub fn other(modified: Option<SystemTime>) -> Self {
+        Self {
+            kind: EntryKind::Other,
+            len: 0,
+            modified,
+            accessed: None,
+            mode: None,
+            owner: None,
+        }
+    }
+
+    /// Set the Unix permission bits of the entry
+    pub fn with_mode(mut self, mode: u32) -> Self {
+        self.mode = Some(mode);
+        self
+    }
+
+    /// Set the last access time of the entry
+    pub fn with_accessed(mut self, accessed: SystemTime) -> Self {
+        self.accessed = Some(accessed);
+        self
+    }
+
+    /// Set the user and group ids owning the entry
+    pub fn with_owner(mut self, uid: u32, gid: u32) -> Self {
+        self.owner = Some((uid, gid));
+        self
+    }
+
+    pub fn is_file(&self) -> bool {
+        self.kind == EntryKind::File
+    }
+
+    pub fn is_folder(&self) -> bool {
+        self.kind == EntryKind::Folder
+    }
+
+    /// The size of a file in bytes
+    pub fn len(&self) -> u64 {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn modified(&self) -> Option<SystemTime> {
+        self.modified
+    }
+
+    pub fn accessed(&self) -> Option<SystemTime> {
+        self.accessed
+    }
+
+    /// The Unix permission bits, if known
+    pub fn mode(&self) -> Option<u32> {
+        self.mode
+    }
+
+    /// The user and group ids owning the entry, if known
+    pub fn owner(&self) -> Option<(u32, u32)> {
+        self.owner
+    }
+}
@@ -0,0 +1,167 @@
+use crate::{EntryMetadata, FileSystem};
+#[cfg(feature = "filetime")]
+use filetime::FileTime;
+#[cfg(all(feature = "xattr", unix))]
+use std::ffi::{OsStr, OsString};
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+#[cfg(feature = "filetime")]
+use std::time::SystemTime;
+
+/// A [`FileSystem`] backed by [`std::fs`]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StdFileSystem;
+
+impl FileSystem for StdFileSystem {
+    fn metadata(&self, path: &Path) -> std::io::Result<EntryMetadata> {
+        let metadata = std::fs::symlink_metadata(path)?;
+        let file_type = metadata.file_type();
+        let modified = metadata.modified().ok();
+
+        let entry_metadata = if file_type.is_file() {
+            EntryMetadata::file(metadata.len(), modified)
+        } else if file_type.is_dir() {
+            EntryMetadata::folder(modified)
+        } else {
+            EntryMetadata::other(modified)
+        };
+
+        let entry_metadata = match metadata.accessed() {
+            Ok(accessed) => entry_metadata.with_accessed(accessed),
+            Err(_) => entry_metadata,
+        };
+
+        #[cfg(unix)]
+        let entry_metadata = {
+            use std::os::unix::fs::{MetadataExt, PermissionsExt};
+            entry_metadata
+                .with_mode(metadata.permissions().mode() & 0o7777)
+                .with_owner(metadata.uid(), metadata.gid())
+        };
+
+        Ok(entry_metadata)
+    }
+
+    fn resolves_to_folder(&self, path: &Path) -> bool {
+        path.is_dir()
+    }
+
+    fn absolute_path(&self, path: &Path) -> PathBuf {
+        path.canonicalize()
+            .or_else(|_| std::path::absolute(path))
+            .unwrap_or_else(|_| path.to_path_buf())
+    }
+
+    fn read_dir(
+        &self,
+        path: &Path,
+    ) -> std::io::Result<Box<dyn Iterator<Item = std::io::Result<PathBuf>>>> {
+        let entries = std::fs::read_dir(path)?;
+        Ok(Box::new(entries.map(|each_entry| {
+            each_entry.map(|each_entry| each_entry.path())
+        })))
+    }
+
+    fn open(&self, path: &Path) -> std::io::Result<Box<dyn Read>> {
+        Ok(Box::new(std::fs::File::open(path)?))
+    }
+
+    fn create(&self, path: &Path) -> std::io::Result<Box<dyn Write>> {
+        Ok(Box::new(std::fs::File::create(path)?))
+    }
+
+    fn create_dir_all(&self, path: &Path) -> std::io::Result<()> {
+        std::fs::create_dir_all(path)
+    }
+
+    fn remove_file(&self, path: &Path) -> std::io::Result<()> {
+        std::fs::remove_file(path)
+    }
+
+    fn remove_dir_all(&self, path: &Path) -> std::io::Result<()> {
+        std::fs::remove_dir_all(path)
+    }
+
+    fn rename(&self, from: &Path, to: &Path) -> std::io::Result<()> {
+        std::fs::rename(from, to)
+    }
+
+    #[cfg(unix)]
+    fn symlink(&self, target: &Path, link: &Path) -> std::io::Result<()> {
+        std::os::unix::fs::symlink(target, link)
+    }
+
+    #[cfg(windows)]
+    fn symlink(&self, target: &Path, link: &Path) -> std::io::Result<()> {
+        let parent = link.parent().unwrap_or_else(|| Path::new(""));
+        if parent.join(target).is_dir() {
+            std::os::windows::fs::symlink_dir(target, link)
+        } else {
+            std::os::windows::fs::symlink_file(target, link)
+        }
+    }
+
+    fn hard_link(&self, original: &Path, link: &Path) -> std::io::Result<()> {
+        std::fs::hard_link(original, link)
+    }
+
+    // copied and moved entries keep their times, other builds leave them unsupported
+    #[cfg(feature = "filetime")]
+    fn set_times(
+        &self,
+        path: &Path,
+        accessed: Option<SystemTime>,
+        modified: Option<SystemTime>,
+    ) -> std::io::Result<()> {
+        let metadata = std::fs::symlink_metadata(path)?;
+        let accessed = match accessed {
+            Some(accessed) => FileTime::from_system_time(accessed),
+            None => FileTime::from_last_access_time(&metadata),
+        };
+        let modified = match modified {
+            Some(modified) => FileTime::from_system_time(modified),
+            None => FileTime::from_last_modification_time(&metadata),
+        };
+        filetime::set_symlink_file_times(path, accessed, modified)
+    }
+
+    #[cfg(unix)]
+    fn set_mode(&self, path: &Path, mode: u32) -> std::io::Result<()> {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::set_permissions(path, std::fs::Permissions::from_mode(mode))
+    }
+
+    #[cfg(unix)]
+    fn set_owner(&self, path: &Path, uid: u32, gid: u32) -> std::io::Result<()> {
+        std::os::unix::fs::lchown(path, Some(uid), Some(gid))
+    }
+
+    #[cfg(all(feature = "xattr", unix))]
+    fn xattrs(&self, path: &Path) -> std::io::Result<Vec<(OsString, Vec<u8>)>> {
+        let mut attributes = vec![];
+        for name in xattr::list(path)? {
+            if let Some(value) = xattr::get(path, &name)? {
+                attributes.push((name, value));
+            }
+        }
+        Ok(attributes)
+    }
+
+    #[cfg(all(feature = "xattr", unix))]
+    fn set_xattr(&self, path: &Path, name: &OsStr, value: &[u8]) -> std::io::Result<()> {
+        xattr::set(path, name, value)
+    }
+
+    fn copy_file(&self, from: &Path, to: &Path) -> std::io::Result<u64> {
+        std::fs::copy(from, to)
+    }
+
+    #[cfg(feature = "fs_extra")]
+    fn copy_folder(&self, from: &Path, to: &Path) -> crate::Result<()> {
+        let mut options = fs_extra::dir::CopyOptions::new();
+        options.copy_inside = true;
+
+        fs_extra::dir::copy(from, to, &options).map_err(crate::FileMatcherError::FsExtraError)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,222 @@
+#[cfg(not(feature = "tar"))]
+compile_error!("Please select a tar feature to build with tar support");
+
+use crate::file_system::archive_entries::{read_only, ArchiveEntries};
+use crate::{EntryMetadata, FileSystem, Result};
+use std::fs::File;
+use std::io::{BufReader, Cursor, Error, ErrorKind, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, UNIX_EPOCH};
+use tar::{Archive, EntryType};
+
+/// Represents a compression of a tar archive
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum TarCompression {
+    None,
+    #[cfg(feature = "tar-gz")]
+    Gzip,
+    #[cfg(feature = "tar-xz")]
+    Xz,
+    #[cfg(feature = "tar-zstd")]
+    Zstd,
+}
+
+impl TarCompression {
+    /// Detect a compression from the extension of an archive such as `.tar.gz` or `.tgz`,
+    /// returns None if the extension is unknown or its compression is not enabled
+    pub fn from_path(path: impl AsRef<Path>) -> Option<Self> {
+        let file_name = path.as_ref().file_name()?.to_str()?.to_lowercase();
+
+        if file_name.ends_with(".tar") {
+            return Some(Self::None);
+        }
+        #[cfg(feature = "tar-gz")]
+        if file_name.ends_with(".tar.gz") || file_name.ends_with(".tgz") {
+            return Some(Self::Gzip);
+        }
+        #[cfg(feature = "tar-xz")]
+        if file_name.ends_with(".tar.xz") || file_name.ends_with(".txz") {
+            return Some(Self::Xz);
+        }
+        #[cfg(feature = "tar-zstd")]
+        if file_name.ends_with(".tar.zst") || file_name.ends_with(".tzst") {
+            return Some(Self::Zstd);
+        }
+        None
+    }
+
+    fn decoder(&self, file: File) -> std::io::Result<Box<dyn Read>> {
+        let file = BufReader::new(file);
+        let decoder: Box<dyn Read> = match self {
+            Self::None => Box::new(file),
+            #[cfg(feature = "tar-gz")]
+            Self::Gzip => Box::new(flate2::read::GzDecoder::new(file)),
+            #[cfg(feature = "tar-xz")]
+            Self::Xz => Box::new(xz2::read::XzDecoder::new(file)),
+            #[cfg(feature = "tar-zstd")]
+            Self::Zstd => Box::new(zstd::stream::read::Decoder::with_buffer(file)?),
+        };
+        Ok(decoder)
+    }
+}
+
+/// A read-only [`FileSystem`] with the files and folders of a tar archive.
+/// Paths are relative to the root of the archive, which is an empty path.
+/// Files of an uncompressed archive are read in place, compressed archives are decompressed
+/// up to the read file. Symbolic and hard links are ignored
+/// ```no_run
+/// use file_matcher::{FileNamed, FilesNamed, OneEntryCopier, TarFileSystem};
+///
+/// # use file_matcher::Result;
+/// # fn main() -> Result<()> {
+///     let release = TarFileSystem::open("release.tar.gz")?;
+///
+///     // Extract the executable
+///     FileNamed::exact("GlamorousToolkit")
+///         .within("bin")
+///         .with_file_system(release.clone())
+///         .copy_to_file_system("destination", &file_matcher::StdFileSystem)?;
+///
+///     // List the libraries
+///     FilesNamed::wildmatch("*.so")
+///         .within("lib")
+///         .with_file_system(release)
+///         .find()?;
+/// #    Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct TarFileSystem {
+    archive: PathBuf,
+    compression: TarCompression,
+    entries: Arc<ArchiveEntries<TarContent>>,
+}
+
+#[derive(Debug, Clone)]
+struct TarContent {
+    index: usize,
+    position: u64,
+    size: u64,
+}
+
+impl TarFileSystem {
+    /// Open a tar archive detecting its compression from the extension,
+    /// an archive with an unknown extension is considered to be uncompressed
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        Self::open_with_compression(
+            path,
+            TarCompression::from_path(path).unwrap_or(TarCompression::None),
+        )
+    }
+
+    /// Open a tar archive with a given compression and read the list of its entries
+    pub fn open_with_compression(
+        path: impl AsRef<Path>,
+        compression: TarCompression,
+    ) -> Result<Self> {
+        let path = path.as_ref();
+        let mut archive = Archive::new(compression.decoder(File::open(path)?)?);
+        let mut entries = ArchiveEntries::new();
+
+        for (index, entry) in archive.entries()?.enumerate() {
+            let entry = entry?;
+            let header = entry.header();
+            let entry_path = entry.path()?.into_owned();
+            let modified = header
+                .mtime()
+                .ok()
+                .map(|mtime| UNIX_EPOCH + Duration::from_secs(mtime));
+
+            let with_mode = |metadata: EntryMetadata| match header.mode() {
+                Ok(mode) => metadata.with_mode(mode & 0o7777),
+                Err(_) => metadata,
+            };
+
+            match header.entry_type() {
+                EntryType::Directory => {
+                    entries.add_folder(entry_path, with_mode(EntryMetadata::folder(modified)))
+                }
+                EntryType::Regular | EntryType::Continuous => {
+                    let size = entry.size();
+                    entries.add_file(
+                        entry_path,
+                        with_mode(EntryMetadata::file(size, modified)),
+                        TarContent {
+                            index,
+                            position: entry.raw_file_position(),
+                            size,
+                        },
+                    )
+                }
+                _ => {}
+            }
+        }
+
+        Ok(Self {
+            archive: path.to_path_buf(),
+            compression,
+            entries: Arc::new(entries),
+        })
+    }
+
+    /// The path to the archive
+    pub fn archive(&self) -> &Path {
+        self.archive.as_path()
+    }
+
+    pub fn compression(&self) -> TarCompression {
+        self.compression
+    }
+}
+
+impl FileSystem for TarFileSystem {
+    fn metadata(&self, path: &Path) -> std::io::Result<EntryMetadata> {
+        self.entries.metadata(path)
+    }
+
+    fn read_dir(
+        &self,
+        path: &Path,
+    ) -> std::io::Result<Box<dyn Iterator<Item = std::io::Result<PathBuf>>>> {
+        let entries = self.entries.read_dir(path)?;
+        Ok(Box::new(entries.into_iter().map(Ok)))
+    }
+
+    fn open(&self, path: &Path) -> std::io::Result<Box<dyn Read>> {
+        let content = self.entries.content(path)?;
+        let mut file = File::open(&self.archive)?;
+
+        if self.compression == TarCompression::None {
+            file.seek(SeekFrom::Start(content.position))?;
+            return Ok(Box::new(file.take(content.size)));
+        }
+
+        let mut archive = Archive::new(self.compression.decoder(file)?);
+        let mut entry = archive
+            .entries()?
+            .nth(content.index)
+            .ok_or_else(|| Error::from(ErrorKind::NotFound))??;
+
+        let mut buffer = Vec::with_capacity(content.size as usize);
+        entry.read_to_end(&mut buffer)?;
+        Ok(Box::new(Cursor::new(buffer)))
+    }
+
+    fn create(&self, _path: &Path) -> std::io::Result<Box<dyn Write>> {
+        Err(read_only())
+    }
+
+    fn create_dir_all(&self, _path: &Path) -> std::io::Result<()> {
+        Err(read_only())
+    }
+
+    fn remove_file(&self, _path: &Path) -> std::io::Result<()> {
+        Err(read_only())
+    }
+
+    fn remove_dir_all(&self, _path: &Path) -> std::io::Result<()> {
+        Err(read_only())
+    }
+}
@@ -0,0 +1,177 @@
+#[cfg(not(feature = "zip"))]
+compile_error!("Please select a zip feature to build with zip support");
+
+use crate::file_system::archive_entries::{read_only, ArchiveEntries};
+use crate::{EntryMetadata, FileSystem, Result};
+use std::convert::TryFrom;
+use std::fs::File;
+use std::io::{Cursor, Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use zip::ZipArchive;
+
+/// A read-only [`FileSystem`] with the files and folders of a zip archive.
+/// Paths are relative to the root of the archive, which is an empty path.
+/// Clones share the same opened archive
+/// ```no_run
+/// use file_matcher::{FileNamed, ZipFileSystem};
+///
+/// # use file_matcher::Result;
+/// # fn main() -> Result<()> {
+///     let release = ZipFileSystem::open("release.zip")?;
+///
+///     // Check the archive contains exactly one *.image at the top level
+///     FileNamed::wildmatch("*.image")
+///         .within("")
+///         .with_file_system(release)
+///         .find()?;
+/// #    Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ZipFileSystem {
+    archive: Arc<Mutex<ZipArchive<File>>>,
+    entries: Arc<ArchiveEntries<usize>>,
+}
+
+impl ZipFileSystem {
+    /// Open a zip archive and read the list of its entries
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        let mut archive = ZipArchive::new(File::open(path.as_ref())?)?;
+        let mut entries = ArchiveEntries::new();
+
+        for index in 0..archive.len() {
+            let entry = archive.by_index_raw(index)?;
+            let modified = entry.last_modified().and_then(|date_time| {
+                system_time(
+                    date_time.year(),
+                    date_time.month(),
+                    date_time.day(),
+                    date_time.hour(),
+                    date_time.minute(),
+                    date_time.second(),
+                )
+            });
+
+            let with_mode = |metadata: EntryMetadata| match entry.unix_mode() {
+                Some(mode) => metadata.with_mode(mode & 0o7777),
+                None => metadata,
+            };
+
+            if entry.is_dir() {
+                entries.add_folder(entry.name(), with_mode(EntryMetadata::folder(modified)));
+            } else if entry.is_file() {
+                entries.add_file(
+                    entry.name(),
+                    with_mode(EntryMetadata::file(entry.size(), modified)),
+                    index,
+                );
+            }
+        }
+
+        Ok(Self {
+            archive: Arc::new(Mutex::new(archive)),
+            entries: Arc::new(entries),
+        })
+    }
+}
+
+impl FileSystem for ZipFileSystem {
+    fn metadata(&self, path: &Path) -> std::io::Result<EntryMetadata> {
+        self.entries.metadata(path)
+    }
+
+    fn read_dir(
+        &self,
+        path: &Path,
+    ) -> std::io::Result<Box<dyn Iterator<Item = std::io::Result<PathBuf>>>> {
+        let entries = self.entries.read_dir(path)?;
+        Ok(Box::new(entries.into_iter().map(Ok)))
+    }
+
+    fn open(&self, path: &Path) -> std::io::Result<Box<dyn Read>> {
+        let index = *self.entries.content(path)?;
+        let mut archive = self.archive.lock().unwrap_or_else(PoisonError::into_inner);
+        let mut file = archive.by_index(index)?;
+
+        let mut buffer = Vec::with_capacity(file.size() as usize);
+        file.read_to_end(&mut buffer)?;
+        Ok(Box::new(Cursor::new(buffer)))
+    }
+
+    fn create(&self, _path: &Path) -> std::io::Result<Box<dyn Write>> {
+        Err(read_only())
+    }
+
+    fn create_dir_all(&self, _path: &Path) -> std::io::Result<()> {
+        Err(read_only())
+    }
+
+    fn remove_file(&self, _path: &Path) -> std::io::Result<()> {
+        Err(read_only())
+    }
+
+    fn remove_dir_all(&self, _path: &Path) -> std::io::Result<()> {
+        Err(read_only())
+    }
+}
+
+/// Converts a date and time of a zip entry, which has no time zone, as if it was in UTC
+fn system_time(
+    year: u16,
+    month: u8,
+    day: u8,
+    hour: u8,
+    minute: u8,
+    second: u8,
+) -> Option<SystemTime> {
+    // days since 1970-01-01 in the proleptic Gregorian calendar
+    let year = i64::from(year) - i64::from(month <= 2);
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month_from_march = (i64::from(month) + 9) % 12;
+    let day_of_year = (153 * month_from_march + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    let days = era * 146097 + day_of_era - 719468;
+
+    let seconds =
+        days * 86400 + i64::from(hour) * 3600 + i64::from(minute) * 60 + i64::from(second);
+    u64::try_from(seconds)
+        .ok()
+        .map(|seconds| UNIX_EPOCH + Duration::from_secs(seconds))
+}
+
+/// Converts a system time to a date and time of a zip entry in UTC,
+/// returns None if it is out of the range supported by zip
+pub(crate) fn zip_date_time(time: SystemTime) -> Option<zip::DateTime> {
+    let seconds = i64::try_from(time.duration_since(UNIX_EPOCH).ok()?.as_secs()).ok()?;
+    let days = seconds.div_euclid(86400);
+    let seconds_of_day = seconds.rem_euclid(86400);
+
+    // the proleptic Gregorian calendar date of the days since 1970-01-01
+    let days = days + 719468;
+    let era = days.div_euclid(146097);
+    let day_of_era = days - era * 146097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_from_march = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_from_march + 2) / 5 + 1;
+    let month = if month_from_march < 10 {
+        month_from_march + 3
+    } else {
+        month_from_march - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    zip::DateTime::from_date_and_time(
+        u16::try_from(year).ok()?,
+        month as u8,
+        day as u8,
+        (seconds_of_day / 3600) as u8,
+        (seconds_of_day % 3600 / 60) as u8,
+        (seconds_of_day % 60) as u8,
+    )
+    .ok()
+}
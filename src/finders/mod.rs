@@ -1,3 +1,7 @@
+pub(crate) mod name_matcher;
+#[cfg(feature = "parallel")]
+pub(crate) mod parallel_finder;
+pub(crate) mod recursive_finder;
 #[cfg(feature = "regex")]
 pub(crate) mod regex_finder;
 #[cfg(feature = "wildmatch")]
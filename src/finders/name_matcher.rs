@@ -0,0 +1,71 @@
+use crate::{EntryName, Result};
+use std::path::Path;
+
+/// A compiled [`EntryName`] that can be matched against the names of many entries
+#[derive(Debug, Clone)]
+pub(crate) enum EntryNameMatcher {
+    Exact(String),
+    Any(Vec<String>),
+    AnyNamed(Vec<EntryNameMatcher>),
+    #[cfg(feature = "regex")]
+    Regex(regex::Regex),
+    #[cfg(feature = "wildmatch")]
+    Wildmatch(wildmatch::WildMatch),
+}
+
+impl EntryNameMatcher {
+    pub fn new(entry_name: &EntryName) -> Result<Self> {
+        let matcher = match entry_name {
+            EntryName::Exact(name) => Self::Exact(name.clone()),
+            EntryName::Any(names) => Self::Any(names.clone()),
+            EntryName::AnyNamed(entry_names) => Self::AnyNamed(
+                entry_names
+                    .iter()
+                    .map(Self::new)
+                    .collect::<Result<Vec<Self>>>()?,
+            ),
+            #[cfg(feature = "regex")]
+            EntryName::Regex(regex_pattern) => Self::Regex(regex::Regex::new(regex_pattern)?),
+            #[cfg(feature = "wildmatch")]
+            EntryName::Wildmatch(wildmatch_pattern) => {
+                Self::Wildmatch(wildmatch::WildMatch::new(wildmatch_pattern))
+            }
+        };
+        Ok(matcher)
+    }
+
+    /// Returns true if an entry found within a search folder matches.
+    /// Exact names with several components, such as `cat/file.txt`, are matched against
+    /// the path relative to the search folder, like they are found without recursion.
+    /// Other names and patterns are matched against the file name.
+    /// Returns false if the path has no file name or it is not a valid Unicode
+    pub fn matches_path(&self, directory: &Path, path: &Path) -> bool {
+        match self {
+            Self::Exact(name) => matches_name(name, directory, path),
+            Self::Any(names) => names.iter().any(|each| matches_name(each, directory, path)),
+            Self::AnyNamed(matchers) => matchers
+                .iter()
+                .any(|each| each.matches_path(directory, path)),
+            #[cfg(feature = "regex")]
+            Self::Regex(regex) => file_name(path).is_some_and(|name| regex.is_match(name)),
+            #[cfg(feature = "wildmatch")]
+            Self::Wildmatch(wildmatch) => {
+                file_name(path).is_some_and(|name| wildmatch.matches(name))
+            }
+        }
+    }
+}
+
+fn matches_name(name: &str, directory: &Path, path: &Path) -> bool {
+    let name = Path::new(name);
+    if name.components().count() > 1 {
+        path.strip_prefix(directory)
+            .is_ok_and(|relative| relative == name)
+    } else {
+        path.file_name() == Some(name.as_os_str())
+    }
+}
+
+fn file_name(path: &Path) -> Option<&str> {
+    path.file_name().and_then(|file_name| file_name.to_str())
+}
@@ -0,0 +1,103 @@
+#[cfg(not(feature = "parallel"))]
+compile_error!("Please select a parallel feature to build with parallel support");
+
+use crate::finders::name_matcher::EntryNameMatcher;
+use crate::utils::{is_readable_entry, is_readable_folder};
+use crate::{EntryType, FileSystem, Result};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{channel, Receiver, Sender};
+use std::sync::Arc;
+
+struct ParallelSearch {
+    file_system: Arc<dyn FileSystem>,
+    entry_type: EntryType,
+    matcher: EntryNameMatcher,
+    directory: PathBuf,
+    cancelled: AtomicBool,
+}
+
+/// Walks a directory and all of its nested folders reading each folder in a separate task of the rayon thread pool.
+/// The entries of a given type with a matching name are yielded as soon as they are found,
+/// in no particular order. Dropping the iterator stops the search
+pub(crate) struct ParallelEntries {
+    search: Arc<ParallelSearch>,
+    entries: Receiver<Result<PathBuf>>,
+}
+
+impl ParallelEntries {
+    pub fn new(
+        file_system: &Arc<dyn FileSystem>,
+        entry_type: &EntryType,
+        matcher: EntryNameMatcher,
+        directory: PathBuf,
+    ) -> Self {
+        let search = Arc::new(ParallelSearch {
+            file_system: file_system.clone(),
+            entry_type: entry_type.clone(),
+            matcher,
+            directory: directory.clone(),
+            cancelled: AtomicBool::new(false),
+        });
+        let (sender, entries) = channel();
+
+        spawn_folder_search(search.clone(), directory, sender);
+
+        Self { search, entries }
+    }
+}
+
+impl Iterator for ParallelEntries {
+    type Item = Result<PathBuf>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.entries.recv().ok()
+    }
+}
+
+impl Drop for ParallelEntries {
+    fn drop(&mut self) {
+        self.search.cancelled.store(true, Ordering::Relaxed);
+    }
+}
+
+fn spawn_folder_search(
+    search: Arc<ParallelSearch>,
+    folder: PathBuf,
+    sender: Sender<Result<PathBuf>>,
+) {
+    rayon::spawn(move || search_folder(search, folder, sender));
+}
+
+fn search_folder(search: Arc<ParallelSearch>, folder: PathBuf, sender: Sender<Result<PathBuf>>) {
+    let entries = match search.file_system.read_dir(&folder) {
+        Ok(entries) => entries,
+        Err(_) => return,
+    };
+
+    for each_entry in entries {
+        if search.cancelled.load(Ordering::Relaxed) {
+            return;
+        }
+
+        let found = match each_entry {
+            Ok(each_path) => {
+                if is_readable_folder(search.file_system.as_ref(), &each_path) {
+                    spawn_folder_search(search.clone(), each_path.clone(), sender.clone());
+                }
+                if is_readable_entry(search.file_system.as_ref(), &search.entry_type, &each_path)
+                    && search.matcher.matches_path(&search.directory, &each_path)
+                {
+                    Ok(each_path)
+                } else {
+                    continue;
+                }
+            }
+            Err(error) => Err(error.into()),
+        };
+
+        if sender.send(found).is_err() {
+            return;
+        }
+    }
+}
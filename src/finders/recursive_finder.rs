@@ -0,0 +1,67 @@
+use crate::finders::name_matcher::EntryNameMatcher;
+use crate::utils::{is_readable_entry, is_readable_folder};
+use crate::{EntryType, FileSystem, Result};
+use std::path::PathBuf;
+use std::sync::Arc;
+
+/// Lazily walks a directory and all of its nested folders one folder at a time,
+/// yielding the entries of a given type with a matching name.
+/// Symbolic links to folders are not followed, folders that are not readable are skipped
+pub(crate) struct RecursiveEntries {
+    file_system: Arc<dyn FileSystem>,
+    entry_type: EntryType,
+    matcher: EntryNameMatcher,
+    directory: PathBuf,
+    folders: Vec<PathBuf>,
+    entries: Option<Box<dyn Iterator<Item = std::io::Result<PathBuf>>>>,
+}
+
+impl RecursiveEntries {
+    pub fn new(
+        file_system: &Arc<dyn FileSystem>,
+        entry_type: &EntryType,
+        matcher: EntryNameMatcher,
+        directory: PathBuf,
+    ) -> Self {
+        Self {
+            file_system: file_system.clone(),
+            entry_type: entry_type.clone(),
+            matcher,
+            folders: vec![directory.clone()],
+            directory,
+            entries: None,
+        }
+    }
+}
+
+impl Iterator for RecursiveEntries {
+    type Item = Result<PathBuf>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.entries.as_mut() {
+                Some(entries) => match entries.next() {
+                    Some(Ok(each_path)) => {
+                        if is_readable_folder(self.file_system.as_ref(), &each_path) {
+                            self.folders.push(each_path.clone());
+                        }
+                        if is_readable_entry(
+                            self.file_system.as_ref(),
+                            &self.entry_type,
+                            &each_path,
+                        ) && self.matcher.matches_path(&self.directory, &each_path)
+                        {
+                            return Some(Ok(each_path));
+                        }
+                    }
+                    Some(Err(error)) => return Some(Err(error.into())),
+                    None => self.entries = None,
+                },
+                None => {
+                    let folder = self.folders.pop()?;
+                    self.entries = self.file_system.read_dir(&folder).ok();
+                }
+            }
+        }
+    }
+}
@@ -2,26 +2,39 @@
 compile_error!("Please select a wildmatch feature to build with wildmatch support");
 
 use crate::utils::readable_entries_in_folder;
-use crate::{EntryType, Result};
+use crate::{EntryType, FileSystem, Result};
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 use wildmatch::WildMatch;
 
 pub(crate) fn find_entries_in_directory_matching(
+    file_system: &Arc<dyn FileSystem>,
     entity_type: &EntryType,
     entity_name_wildmatch: &str,
     directory: impl AsRef<Path>,
 ) -> Result<Vec<PathBuf>> {
+    entries_in_directory_matching(file_system, entity_type, entity_name_wildmatch, directory)?
+        .collect()
+}
+
+/// Lazily iterates over the entries within a directory with a name matching a given wildmatch
+pub(crate) fn entries_in_directory_matching(
+    file_system: &Arc<dyn FileSystem>,
+    entity_type: &EntryType,
+    entity_name_wildmatch: &str,
+    directory: impl AsRef<Path>,
+) -> Result<impl Iterator<Item = Result<PathBuf>>> {
     let compiled_wildmatch = WildMatch::new(entity_name_wildmatch);
 
-    let files = readable_entries_in_folder(entity_type, directory)
-        .into_iter()
-        .filter(|each_path| {
-            each_path.file_name().map_or(false, |file_name| {
-                file_name
-                    .to_str()
-                    .map_or(false, |file_name| compiled_wildmatch.matches(file_name))
+    let entries =
+        readable_entries_in_folder(file_system, entity_type, directory).filter(move |each_entry| {
+            each_entry.as_ref().map_or(true, |each_path| {
+                each_path.file_name().is_some_and(|file_name| {
+                    file_name
+                        .to_str()
+                        .is_some_and(|file_name| compiled_wildmatch.matches(file_name))
+                })
             })
-        })
-        .collect::<Vec<PathBuf>>();
-    Ok(files)
+        });
+    Ok(entries)
 }
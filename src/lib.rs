@@ -1,14 +1,35 @@
+#[cfg(feature = "blake3")]
+extern crate blake3;
+#[cfg(feature = "filetime")]
+extern crate filetime;
 #[cfg(feature = "fs_extra")]
 extern crate fs_extra;
+#[cfg(feature = "notify")]
+extern crate notify;
+#[cfg(feature = "rayon")]
+extern crate rayon;
 #[cfg(feature = "regex")]
 extern crate regex;
+#[cfg(feature = "sha2")]
+extern crate sha2;
+#[cfg(feature = "tar")]
+extern crate tar;
+#[cfg(feature = "tokio")]
+extern crate tokio;
 #[cfg(feature = "wildmatch")]
 extern crate wildmatch;
+#[cfg(all(feature = "xattr", unix))]
+extern crate xattr;
+#[cfg(feature = "zip")]
+extern crate zip;
 
 mod actions;
 mod alias;
+#[cfg(any(feature = "sha256", feature = "blake3"))]
+mod checksum;
 mod entries;
 mod error;
+mod file_system;
 mod finders;
 mod utils;
 
@@ -22,7 +43,10 @@ pub use actions::*;
 pub use entries::*;
 
 pub use alias::EntityAlias;
+#[cfg(any(feature = "sha256", feature = "blake3"))]
+pub use checksum::{Checksum, ContentHash};
 pub use error::{FileMatcherError, Result};
+pub use file_system::*;
 
 /// Defines a file with various name types.
 /// It is required that there exists exactly one file with a given name description.
@@ -100,6 +124,11 @@ impl OneEntryNamed for FileNamed {
     fn boxed(&self) -> Box<dyn OneEntryNamed> {
         Box::new(self.clone())
     }
+
+    #[cfg(any(feature = "sha256", feature = "blake3"))]
+    fn content_hash(&self) -> Option<&ContentHash> {
+        self.0.content_hash()
+    }
 }
 
 impl FileNamed {
@@ -132,6 +161,14 @@ impl FileNamed {
         Self(EntryNamed::file(EntryName::Wildmatch(pattern.into())))
     }
 
+    /// Only match files whose content has a given checksum, written as a hexadecimal string.
+    /// The checksum is applied to the entries found within any folder
+    #[cfg(any(feature = "sha256", feature = "blake3"))]
+    pub fn with_content_hash(mut self, checksum: Checksum, hex: impl Into<String>) -> Self {
+        self.0 = self.0.with_content_hash(ContentHash::new(checksum, hex));
+        self
+    }
+
     pub fn within(&self, directory: impl Into<PathBuf>) -> OneEntry {
         self.within_path_buf(directory.into())
     }
@@ -283,6 +320,11 @@ impl ManyEntriesNamed for FilesNamed {
     fn boxed(&self) -> Box<dyn ManyEntriesNamed> {
         Box::new(self.clone())
     }
+
+    #[cfg(any(feature = "sha256", feature = "blake3"))]
+    fn content_hash(&self) -> Option<&ContentHash> {
+        self.0.content_hash()
+    }
 }
 
 impl FilesNamed {
@@ -306,6 +348,14 @@ impl FilesNamed {
         Self(EntryNamed::file(EntryName::Wildmatch(pattern.into())))
     }
 
+    /// Only match files whose content has a given checksum, written as a hexadecimal string.
+    /// The checksum is applied to the entries found within any folder
+    #[cfg(any(feature = "sha256", feature = "blake3"))]
+    pub fn with_content_hash(mut self, checksum: Checksum, hex: impl Into<String>) -> Self {
+        self.0 = self.0.with_content_hash(ContentHash::new(checksum, hex));
+        self
+    }
+
     pub fn within(&self, directory: impl Into<PathBuf>) -> ManyEntries {
         ManyEntries::new(self.boxed(), directory)
     }
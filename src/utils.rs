@@ -1,37 +1,55 @@
-use crate::EntryType;
-use std::fs::symlink_metadata;
+use crate::{EntryType, FileSystem, Result};
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
+/// Lazily iterates over the entries of a given type within a folder.
+/// Entries that failed to be read are returned as errors,
+/// a folder that is not readable or does not exist has no entries
 pub(crate) fn readable_entries_in_folder(
+    file_system: &Arc<dyn FileSystem>,
     entity_type: &EntryType,
     folder: impl AsRef<Path>,
-) -> Vec<PathBuf> {
-    folder.as_ref().read_dir().map_or(vec![], |dir| {
-        dir.filter(|each_entry| each_entry.is_ok())
-            .map(|each_entry| each_entry.unwrap())
-            .map(|each_entry| each_entry.path())
-            .filter(|each_path| is_readable_entry(entity_type, each_path))
-            .collect::<Vec<PathBuf>>()
-    })
+) -> impl Iterator<Item = Result<PathBuf>> {
+    let file_system = file_system.clone();
+    let entity_type = entity_type.clone();
+    file_system
+        .read_dir(folder.as_ref())
+        .into_iter()
+        .flatten()
+        .filter_map(move |each_entry| match each_entry {
+            Ok(each_path) => {
+                if is_readable_entry(file_system.as_ref(), &entity_type, &each_path) {
+                    Some(Ok(each_path))
+                } else {
+                    None
+                }
+            }
+            Err(error) => Some(Err(error.into())),
+        })
 }
 
 /// Returns true if a given Path entry is an entry of the given type.
 /// returns false if a given entry is not readable or does not exist
-pub(crate) fn is_readable_entry(entity_type: &EntryType, entry: impl AsRef<Path>) -> bool {
+pub(crate) fn is_readable_entry(
+    file_system: &dyn FileSystem,
+    entity_type: &EntryType,
+    entry: impl AsRef<Path>,
+) -> bool {
     let entry = entry.as_ref();
     match entity_type {
-        EntryType::File => is_readable_file(entry),
-        EntryType::Folder => is_readable_folder(entry),
-        EntryType::Any => is_readable_file(entry) || is_readable_folder(entry),
+        EntryType::File => is_readable_file(file_system, entry),
+        EntryType::Folder => is_readable_folder(file_system, entry),
+        EntryType::Any => {
+            is_readable_file(file_system, entry) || is_readable_folder(file_system, entry)
+        }
     }
 }
 
 /// Returns true if a given Path entry is a file (not a symlink and not a folder),
 /// returns false if a given entry is not readable or does not exist
-pub(crate) fn is_readable_file(entry: impl AsRef<Path>) -> bool {
-    if let Ok(metadata) = symlink_metadata(entry.as_ref()) {
-        let file_type = metadata.file_type();
-        file_type.is_file()
+pub(crate) fn is_readable_file(file_system: &dyn FileSystem, entry: impl AsRef<Path>) -> bool {
+    if let Ok(metadata) = file_system.metadata(entry.as_ref()) {
+        metadata.is_file()
     } else {
         false
     }
@@ -39,11 +57,148 @@ pub(crate) fn is_readable_file(entry: impl AsRef<Path>) -> bool {
 
 /// Returns true if a given Path entry is a folder (not a symlink and not a file),
 /// returns false if a given entry is not readable or does not exist
-pub(crate) fn is_readable_folder(entry: impl AsRef<Path>) -> bool {
-    if let Ok(metadata) = symlink_metadata(entry.as_ref()) {
-        let file_type = metadata.file_type();
-        file_type.is_dir()
+pub(crate) fn is_readable_folder(file_system: &dyn FileSystem, entry: impl AsRef<Path>) -> bool {
+    if let Ok(metadata) = file_system.metadata(entry.as_ref()) {
+        metadata.is_folder()
     } else {
         false
     }
 }
+
+/// Runs a blocking file system operation on the tokio blocking thread pool
+#[cfg(feature = "async")]
+pub(crate) async fn spawn_blocking<T: Send + 'static>(
+    operation: impl FnOnce() -> Result<T> + Send + 'static,
+) -> Result<T> {
+    tokio::task::spawn_blocking(operation)
+        .await
+        .map_err(std::io::Error::from)?
+}
+
+/// Returns the file name of an entry, which is expected to be valid unicode
+#[cfg(any(
+    feature = "copier",
+    feature = "mover",
+    feature = "linker",
+    feature = "syncer",
+    feature = "zip",
+    feature = "tar"
+))]
+pub(crate) fn entry_file_name(entry: &Path) -> Result<&str> {
+    use crate::FileMatcherError;
+
+    if let Some(file_name) = entry.file_name() {
+        if let Some(file_name) = file_name.to_str() {
+            Ok(file_name)
+        } else {
+            FileMatcherError::InvalidUnicode(file_name.to_os_string()).into()
+        }
+    } else {
+        FileMatcherError::NotReadable(entry.to_path_buf()).into()
+    }
+}
+
+/// Returns the path of an entry relative to the folder it was found within, so that
+/// the entries found within nested folders keep their structure instead of colliding.
+/// An entry outside of the folder is named by its file name
+#[cfg(any(
+    feature = "copier",
+    feature = "linker",
+    feature = "zip",
+    feature = "tar"
+))]
+pub(crate) fn entry_relative_path<'a>(directory: &Path, entry: &'a Path) -> Result<&'a str> {
+    use crate::FileMatcherError;
+
+    match entry.strip_prefix(directory) {
+        Ok(relative) if relative.file_name().is_some() => match relative.to_str() {
+            Some(relative) => Ok(relative),
+            None => FileMatcherError::InvalidUnicode(relative.as_os_str().to_os_string()).into(),
+        },
+        _ => entry_file_name(entry),
+    }
+}
+
+/// Lexically normalizes a path removing the current folder components
+/// and resolving the parent folder components where possible
+#[cfg(any(feature = "remover", feature = "linker"))]
+pub(crate) fn normalize_path(path: impl AsRef<Path>) -> PathBuf {
+    use std::path::Component;
+
+    let mut normalized = PathBuf::new();
+    for component in path.as_ref().components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => match normalized.components().next_back() {
+                Some(Component::Normal(_)) => {
+                    normalized.pop();
+                }
+                Some(Component::RootDir) | Some(Component::Prefix(_)) => {}
+                _ => normalized.push(component),
+            },
+            _ => normalized.push(component),
+        }
+    }
+    normalized
+}
+
+/// Lexically computes a path to `to` relative to the `from` folder.
+/// Returns `None` if one of the paths is absolute and the other is not
+/// or if `from` contains parent folder components that can not be resolved
+#[cfg(feature = "linker")]
+pub(crate) fn relative_path(from: impl AsRef<Path>, to: impl AsRef<Path>) -> Option<PathBuf> {
+    use std::path::Component;
+
+    let from = normalize_path(from);
+    let to = normalize_path(to);
+
+    if from.has_root() != to.has_root() {
+        return None;
+    }
+
+    let mut from_components = from.components().peekable();
+    let mut to_components = to.components().peekable();
+    while let (Some(from_component), Some(to_component)) =
+        (from_components.peek(), to_components.peek())
+    {
+        if from_component != to_component {
+            break;
+        }
+        from_components.next();
+        to_components.next();
+    }
+
+    let mut relative = PathBuf::new();
+    for component in from_components {
+        match component {
+            Component::Normal(_) => relative.push(".."),
+            _ => return None,
+        }
+    }
+    relative.extend(to_components);
+    Some(relative)
+}
+
+/// A hidden path next to `path` that is unique within the process
+#[cfg(any(
+    feature = "copier",
+    feature = "mover",
+    feature = "zip",
+    feature = "tar",
+    all(feature = "linker", any(feature = "sha256", feature = "blake3"))
+))]
+pub(crate) fn temporary_sibling(path: &Path) -> PathBuf {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+    let name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    path.with_file_name(format!(
+        ".{}.{}-{}.tmp",
+        name,
+        std::process::id(),
+        COUNTER.fetch_add(1, Ordering::Relaxed)
+    ))
+}
@@ -0,0 +1,347 @@
+#![cfg(all(feature = "zip", feature = "tar"))]
+
+extern crate tempdir;
+
+mod common;
+
+use common::{FailingFileSystem, Failure};
+use file_matcher::{
+    ArchiveFormat, FileMatcherError, FileNamed, FileSystem, FilesNamed, FolderNamed,
+    ManyEntriesArchiver, ManyEntriesCopier, MemoryFileSystem, OneEntryArchiver, OneEntryCopier,
+    Operation, Result, StdFileSystem, TarCompression, TarFileSystem, ZipFileSystem,
+};
+use std::path::{Path, PathBuf};
+use tempdir::TempDir;
+
+#[cfg(feature = "zip")]
+#[test]
+pub fn zip_find_one_file_wildmatch() -> Result<()> {
+    let archive = ZipFileSystem::open("tests/archives/assets.zip")?;
+
+    let image = FileNamed::wildmatch("*.image")
+        .within("")
+        .with_file_system(archive)
+        .find()?;
+    assert_eq!(image, Path::new("GlamorousToolkit.image"));
+    Ok(())
+}
+
+#[cfg(feature = "zip")]
+#[test]
+pub fn zip_find_one_folder_exact() -> Result<()> {
+    let archive = ZipFileSystem::open("tests/archives/assets.zip")?;
+
+    let folder = FolderNamed::exact("cat")
+        .within("")
+        .with_file_system(archive)
+        .find()?;
+    assert_eq!(folder, Path::new("cat"));
+    Ok(())
+}
+
+#[cfg(feature = "zip")]
+#[test]
+pub fn zip_find_one_file_too_many() -> Result<()> {
+    let archive = ZipFileSystem::open("tests/archives/assets.zip")?;
+
+    let entry = FileNamed::wildmatch("*.txt")
+        .within("")
+        .with_file_system(archive);
+    match entry.find() {
+        Err(FileMatcherError::TooMany(_)) => {}
+        other => panic!("Should fail with too many, but got {:?}", other),
+    }
+    Ok(())
+}
+
+#[cfg(feature = "zip")]
+#[test]
+pub fn zip_as_bytes() -> Result<()> {
+    let archive = ZipFileSystem::open("tests/archives/assets.zip")?;
+
+    let bytes = FileNamed::exact("file.txt")
+        .within("cat")
+        .with_file_system(archive)
+        .as_bytes()?;
+    assert_eq!(bytes, b"purr");
+    Ok(())
+}
+
+#[cfg(feature = "zip")]
+#[test]
+pub fn zip_many_recursive() -> Result<()> {
+    let archive = ZipFileSystem::open("tests/archives/assets.zip")?;
+
+    let files = FilesNamed::wildmatch("*.txt")
+        .within("")
+        .with_file_system(archive)
+        .recursive()
+        .sorted()
+        .find()?;
+    assert_eq!(
+        files,
+        vec![
+            PathBuf::from("cat/file.txt"),
+            PathBuf::from("cat.txt"),
+            PathBuf::from("dog.txt"),
+        ]
+    );
+    Ok(())
+}
+
+#[cfg(feature = "tar")]
+#[test]
+pub fn tar_find_one_file_exact() -> Result<()> {
+    let archive = TarFileSystem::open("tests/archives/release.tar")?;
+
+    let executable = FileNamed::exact("GlamorousToolkit")
+        .within("bin")
+        .with_file_system(archive.clone())
+        .find()?;
+    assert_eq!(executable, Path::new("bin/GlamorousToolkit"));
+
+    let bytes = FileNamed::exact("libSkia.so")
+        .within("lib")
+        .with_file_system(archive)
+        .as_bytes()?;
+    assert_eq!(bytes, b"skia");
+    Ok(())
+}
+
+#[cfg(feature = "tar")]
+#[test]
+pub fn tar_resolves_parent_folders() -> Result<()> {
+    let archive = TarFileSystem::open("tests/archives/release.tar")?;
+
+    let bytes = FileNamed::exact("libSkia.so")
+        .within("bin/../lib")
+        .with_file_system(archive.clone())
+        .as_bytes()?;
+    assert_eq!(bytes, b"skia");
+
+    let missing = FileNamed::exact("libSkia.so")
+        .within("lib/../bin")
+        .with_file_system(archive)
+        .find();
+    assert!(matches!(missing, Err(FileMatcherError::NotExists(_))));
+    Ok(())
+}
+
+#[cfg(feature = "tar-gz")]
+#[test]
+pub fn tar_gz_many_wildmatch() -> Result<()> {
+    let archive = TarFileSystem::open("tests/archives/release.tar.gz")?;
+    assert_eq!(archive.compression(), TarCompression::Gzip);
+
+    let libraries = FilesNamed::wildmatch("*.so*")
+        .within("lib")
+        .with_file_system(archive)
+        .sorted()
+        .find()?;
+    assert_eq!(
+        libraries,
+        vec![
+            PathBuf::from("lib/libGlamorousToolkit.so"),
+            PathBuf::from("lib/libSkia.so"),
+        ]
+    );
+    Ok(())
+}
+
+#[cfg(all(feature = "tar-gz", feature = "copier"))]
+#[test]
+pub fn tar_gz_extract_one_file_alias() -> Result<()> {
+    let tmp_dir = TempDir::new("test")?;
+    let archive = TarFileSystem::open("tests/archives/release.tar.gz")?;
+
+    let extracted = FileNamed::exact("libSkia.so")
+        .alias("libskia.so")
+        .within("lib")
+        .with_file_system(archive)
+        .copy_to_file_system(tmp_dir.path(), &StdFileSystem)?;
+
+    assert_eq!(extracted, tmp_dir.path().join("libskia.so"));
+    assert_eq!(std::fs::read(&extracted)?, b"skia");
+
+    tmp_dir.close()?;
+    Ok(())
+}
+
+#[cfg(all(feature = "tar-gz", feature = "copier"))]
+#[test]
+pub fn tar_gz_extract_many_entries() -> Result<()> {
+    let tmp_dir = TempDir::new("test")?;
+    let archive = TarFileSystem::open("tests/archives/release.tar.gz")?;
+
+    let extracted = FilesNamed::wildmatch("*.so")
+        .within("lib")
+        .with_file_system(archive.clone())
+        .sorted()
+        .copy_to_file_system(tmp_dir.path().join("lib"), &StdFileSystem)?;
+    assert_eq!(
+        extracted,
+        vec![
+            tmp_dir.path().join("lib").join("libGlamorousToolkit.so"),
+            tmp_dir.path().join("lib").join("libSkia.so"),
+        ]
+    );
+
+    let extracted = FolderNamed::exact("bin")
+        .within("")
+        .with_file_system(archive)
+        .copy_to_file_system(tmp_dir.path(), &StdFileSystem)?;
+    assert_eq!(
+        std::fs::read(extracted.join("GlamorousToolkit"))?,
+        b"#!/bin/sh\n"
+    );
+
+    tmp_dir.close()?;
+    Ok(())
+}
+
+#[cfg(feature = "zip")]
+#[test]
+pub fn zip_archive_one_folder_alias() -> Result<()> {
+    let tmp_dir = TempDir::new("test")?;
+
+    let archive = FolderNamed::exact("cat")
+        .alias("kitty")
+        .within("tests/assets")
+        .archive(tmp_dir.path().join("kitty.zip"), ArchiveFormat::Zip)?;
+
+    let archive = ZipFileSystem::open(archive)?;
+    let file = FileNamed::exact("file.txt")
+        .within("kitty")
+        .with_file_system(archive)
+        .find()?;
+    assert_eq!(file, Path::new("kitty/file.txt"));
+
+    tmp_dir.close()?;
+    Ok(())
+}
+
+#[cfg(feature = "zip")]
+#[test]
+pub fn zip_archive_dry_run() -> Result<()> {
+    let tmp_dir = TempDir::new("test")?;
+    let archive = tmp_dir.path().join("kitty.zip");
+
+    let plan = FolderNamed::exact("cat")
+        .alias("kitty")
+        .within("tests/assets")
+        .archive_dry_run(&archive)?;
+
+    assert_eq!(
+        plan.operations(),
+        &[Operation::Archive {
+            from: Path::new("tests/assets").join("cat"),
+            archive: archive.clone(),
+            name: "kitty".to_string(),
+        }]
+    );
+    assert!(!archive.exists());
+
+    tmp_dir.close()?;
+    Ok(())
+}
+
+#[cfg(feature = "zip")]
+#[test]
+pub fn zip_archive_many_nested_files_with_same_name() -> Result<()> {
+    let tmp_dir = TempDir::new("test")?;
+    let file_system = MemoryFileSystem::new()
+        .with_file("assets/a/x.txt", "meow")
+        .with_file("assets/b/x.txt", "woof");
+
+    let archive = FilesNamed::exact("x.txt")
+        .within("assets")
+        .with_file_system(file_system)
+        .recursive()
+        .archive(tmp_dir.path().join("x.zip"), ArchiveFormat::Zip)?;
+
+    let archive = ZipFileSystem::open(archive)?;
+    let files = FilesNamed::exact("x.txt")
+        .within("")
+        .with_file_system(archive)
+        .recursive()
+        .sorted()
+        .find()?;
+    assert_eq!(
+        files,
+        vec![PathBuf::from("a/x.txt"), PathBuf::from("b/x.txt")]
+    );
+
+    tmp_dir.close()?;
+    Ok(())
+}
+
+#[test]
+pub fn failed_archive_leaves_destination_untouched() -> Result<()> {
+    let tmp_dir = TempDir::new("test")?;
+    let archive = tmp_dir.path().join("assets.tar");
+    std::fs::write(&archive, "previous archive")?;
+
+    let file_system = MemoryFileSystem::new()
+        .with_file("assets/cat.txt", "meow")
+        .with_file("assets/dog.txt", "woof");
+    let result = FilesNamed::wildmatch("*.txt")
+        .within("assets")
+        .with_file_system(FailingFileSystem::new(
+            file_system,
+            Failure::UnreadableFile("dog.txt"),
+        ))
+        .sorted()
+        .archive(&archive, ArchiveFormat::Tar);
+
+    assert!(result.is_err());
+    assert_eq!(std::fs::read_to_string(&archive)?, "previous archive");
+    assert_eq!(std::fs::read_dir(tmp_dir.path())?.count(), 1);
+    Ok(())
+}
+
+#[cfg(feature = "tar-gz")]
+#[test]
+pub fn tar_gz_archive_many_entries() -> Result<()> {
+    let tmp_dir = TempDir::new("test")?;
+    let archive = tmp_dir.path().join("assets.tar.gz");
+
+    FilesNamed::wildmatch("*.txt")
+        .within("tests/assets")
+        .archive(&archive, ArchiveFormat::from_path(&archive).unwrap())?;
+
+    let archive = TarFileSystem::open(archive)?;
+    let files = FilesNamed::wildmatch("*")
+        .within("")
+        .with_file_system(archive)
+        .sorted()
+        .find()?;
+    assert_eq!(
+        files,
+        vec![PathBuf::from("cat.txt"), PathBuf::from("dog.txt")]
+    );
+
+    tmp_dir.close()?;
+    Ok(())
+}
+
+#[cfg(all(unix, feature = "tar-gz"))]
+#[test]
+pub fn tar_gz_archive_preserves_permissions() -> Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let tmp_dir = TempDir::new("test")?;
+    let executable = tmp_dir.path().join("GlamorousToolkit");
+    std::fs::write(&executable, "#!/bin/sh")?;
+    std::fs::set_permissions(&executable, std::fs::Permissions::from_mode(0o755))?;
+
+    let archive = FileNamed::exact("GlamorousToolkit")
+        .within(tmp_dir.path())
+        .archive(tmp_dir.path().join("release.tar.gz"), ArchiveFormat::TarGz)?;
+
+    let archive = TarFileSystem::open(archive)?;
+    let metadata = archive.metadata(Path::new("GlamorousToolkit"))?;
+    assert_eq!(metadata.mode(), Some(0o755));
+
+    tmp_dir.close()?;
+    Ok(())
+}
@@ -0,0 +1,73 @@
+#![cfg(feature = "async")]
+
+extern crate tempdir;
+
+use file_matcher::{
+    FileMatcherError, FileNamed, FileOrFolderNamed, FilesNamed, OneEntryAsyncCopier, Result,
+};
+use tempdir::TempDir;
+
+#[tokio::test]
+pub async fn find_one_file_exact_async() -> Result<()> {
+    let file = FileNamed::exact("cat.txt")
+        .within("tests/assets")
+        .find_async()
+        .await?;
+    assert_eq!(file.file_name().unwrap(), "cat.txt");
+    Ok(())
+}
+
+#[tokio::test]
+pub async fn exists_one_file_exact_async() -> Result<()> {
+    let entry = FileNamed::exact("cat.txt").within("tests/assets");
+    assert!(entry.exists_async().await?);
+
+    let entry = FileNamed::exact("kitty.txt").within("tests/assets");
+    assert!(!entry.exists_async().await?);
+    Ok(())
+}
+
+#[tokio::test]
+pub async fn find_one_file_or_folder_async_error() {
+    let entry = FileOrFolderNamed::wildmatch("cat*").within("tests/assets");
+    match entry.find_async().await {
+        Err(FileMatcherError::TooMany(_)) => {}
+        other => panic!("Should fail with too many, but got {:?}", other),
+    }
+}
+
+#[tokio::test]
+pub async fn as_bytes_async() -> Result<()> {
+    let bytes = FileNamed::exact("cat.txt")
+        .within("tests/assets")
+        .as_bytes_async()
+        .await?;
+    assert!(bytes.is_empty());
+    Ok(())
+}
+
+#[tokio::test]
+pub async fn many_wildmatch_async() -> Result<()> {
+    let files = FilesNamed::wildmatch("*.txt")
+        .within("tests/assets")
+        .find_async()
+        .await?;
+    assert_eq!(files.len(), 2);
+    Ok(())
+}
+
+#[tokio::test]
+pub async fn copy_one_file_exact_alias_async() -> Result<()> {
+    let tmp_dir = TempDir::new("test")?;
+
+    let file = FileNamed::exact("cat.txt")
+        .alias("kitty.txt")
+        .within("tests/assets");
+
+    let copied = file.copy_async(tmp_dir.path()).await?;
+
+    assert_eq!(&copied, &tmp_dir.path().join("kitty.txt"));
+
+    tmp_dir.close()?;
+    Ok(())
+}
@@ -0,0 +1,158 @@
+#![cfg(all(feature = "copier", feature = "mover"))]
+
+extern crate tempdir;
+
+mod common;
+
+use common::{entries, FailingFileSystem, Failure};
+use file_matcher::{
+    CopyOptions, FileNamed, FolderNamed, MemoryFileSystem, OneEntryCopier, OneEntryMover, Result,
+};
+use std::path::{Path, PathBuf};
+use tempdir::TempDir;
+
+fn assets() -> MemoryFileSystem {
+    common::assets().with_file("assets/cat/image.bin", vec![7u8; 1024])
+}
+
+#[test]
+pub fn copy_file_atomically() -> Result<()> {
+    let file_system = assets();
+
+    let copy = FileNamed::exact("cat.txt")
+        .within("assets")
+        .with_file_system(file_system.clone())
+        .copy_with_options("destination", CopyOptions::new().atomic())?;
+
+    assert_eq!(copy, PathBuf::from("destination/cat.txt"));
+    assert_eq!(file_system.read("destination/cat.txt")?, b"meow");
+    assert_eq!(
+        entries(&file_system, Path::new("destination"))?,
+        vec![PathBuf::from("destination/cat.txt")]
+    );
+    Ok(())
+}
+
+#[test]
+pub fn copy_file_atomically_replaces_existing_file() -> Result<()> {
+    let tmp_dir = TempDir::new("test")?;
+    let destination = tmp_dir.path().join("kitty.txt");
+    std::fs::write(&destination, "hiss")?;
+
+    let copy = FileNamed::exact("cat.txt")
+        .within("tests/assets")
+        .copy_with_options(&destination, CopyOptions::new().atomic())?;
+
+    assert_eq!(copy, destination);
+    assert_eq!(
+        std::fs::read(&destination)?,
+        std::fs::read("tests/assets/cat.txt")?
+    );
+    assert_eq!(std::fs::read_dir(tmp_dir.path())?.count(), 1);
+    Ok(())
+}
+
+#[test]
+pub fn copy_folder_atomically() -> Result<()> {
+    let tmp_dir = TempDir::new("test")?;
+
+    let copy = FolderNamed::exact("cat")
+        .within("tests/assets")
+        .copy_with_options(tmp_dir.path(), CopyOptions::new().atomic())?;
+
+    assert_eq!(copy, tmp_dir.path().join("cat"));
+    assert!(copy.join("file.txt").exists());
+    assert_eq!(std::fs::read_dir(tmp_dir.path())?.count(), 1);
+    Ok(())
+}
+
+#[test]
+pub fn interrupted_atomic_copy_of_file_leaves_nothing() -> Result<()> {
+    let file_system = assets();
+
+    let result = FileNamed::exact("cat.txt")
+        .within("assets")
+        .with_file_system(FailingFileSystem::new(
+            file_system.clone(),
+            Failure::InterruptedCopy,
+        ))
+        .copy_with_options("destination", CopyOptions::new().atomic());
+
+    assert!(result.is_err());
+    assert!(entries(&file_system, Path::new("destination"))?.is_empty());
+    Ok(())
+}
+
+#[test]
+pub fn interrupted_atomic_copy_of_folder_leaves_nothing() -> Result<()> {
+    let file_system = assets();
+
+    let result = FolderNamed::exact("cat")
+        .within("assets")
+        .with_file_system(FailingFileSystem::new(
+            file_system.clone(),
+            Failure::InterruptedCopy,
+        ))
+        .copy_with_options("destination", CopyOptions::new().atomic());
+
+    assert!(result.is_err());
+    assert!(entries(&file_system, Path::new("destination"))?.is_empty());
+    Ok(())
+}
+
+#[test]
+pub fn interrupted_copy_leaves_partial_file() -> Result<()> {
+    let file_system = assets();
+
+    let result = FileNamed::exact("cat.txt")
+        .within("assets")
+        .with_file_system(FailingFileSystem::new(
+            file_system.clone(),
+            Failure::InterruptedCopy,
+        ))
+        .copy("destination");
+
+    assert!(result.is_err());
+    assert_eq!(file_system.read("destination/cat.txt")?, b"m");
+    Ok(())
+}
+
+#[test]
+pub fn interrupted_atomic_move_keeps_source() -> Result<()> {
+    let file_system = assets();
+
+    let result = FolderNamed::exact("cat")
+        .within("assets")
+        .with_file_system(FailingFileSystem::new(
+            file_system.clone(),
+            Failure::InterruptedCopy,
+        ))
+        .move_with_options("destination", CopyOptions::new().atomic());
+
+    assert!(result.is_err());
+    assert_eq!(file_system.read("assets/cat/file.txt")?, b"purr");
+    assert!(entries(&file_system, Path::new("destination"))?.is_empty());
+    Ok(())
+}
+
+#[test]
+pub fn move_folder_atomically() -> Result<()> {
+    let file_system = assets();
+
+    let moved = FolderNamed::exact("cat")
+        .within("assets")
+        .with_file_system(file_system.clone())
+        .move_with_options("destination", CopyOptions::new().atomic())?;
+
+    assert_eq!(moved, PathBuf::from("destination/cat"));
+    assert!(!file_system.contains("assets/cat"));
+    assert_eq!(
+        entries(&file_system, Path::new("destination"))?,
+        vec![
+            PathBuf::from("destination/cat"),
+            PathBuf::from("destination/cat/file.txt"),
+            PathBuf::from("destination/cat/image.bin"),
+        ]
+    );
+    Ok(())
+}
@@ -0,0 +1,238 @@
+#![cfg(all(
+    feature = "copier",
+    feature = "mover",
+    feature = "sha256",
+    feature = "blake3"
+))]
+
+mod common;
+
+use common::{FailingFileSystem, Failure};
+use file_matcher::{
+    Checksum, CopyOptions, FileMatcherError, FileNamed, FilesNamed, FolderNamed, MemoryFileSystem,
+    OneEntryCopier, OneEntryMover, Result,
+};
+use std::path::PathBuf;
+
+fn assets() -> MemoryFileSystem {
+    common::assets().with_file("assets/cat/image.bin", vec![7u8; 100 * 1024])
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+#[test]
+pub fn checksum_of_reader() -> Result<()> {
+    assert_eq!(
+        hex(&Checksum::Sha256.of_reader("abc".as_bytes())?),
+        "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+    );
+    assert_eq!(
+        hex(&Checksum::Blake3.of_reader("abc".as_bytes())?),
+        "6437b3ac38465133ffb63b75273a8db548c558465d79db03fd359c6cd5bd9d85"
+    );
+    Ok(())
+}
+
+#[test]
+pub fn copy_file_verified() -> Result<()> {
+    let file_system = assets();
+
+    let copy = FileNamed::exact("cat.txt")
+        .within("assets")
+        .with_file_system(file_system.clone())
+        .copy_with_options("destination", CopyOptions::new().verify(Checksum::Sha256))?;
+
+    assert_eq!(file_system.read(copy)?, b"meow");
+    Ok(())
+}
+
+#[test]
+pub fn copy_folder_verified() -> Result<()> {
+    let file_system = assets();
+
+    let copy = FolderNamed::exact("cat")
+        .within("assets")
+        .with_file_system(file_system.clone())
+        .copy_with_options("destination", CopyOptions::new().verify(Checksum::Blake3))?;
+
+    assert_eq!(copy, PathBuf::from("destination/cat"));
+    assert_eq!(file_system.read("destination/cat/file.txt")?, b"purr");
+    Ok(())
+}
+
+#[test]
+pub fn copy_truncated_file_fails() -> Result<()> {
+    let result = FileNamed::exact("cat.txt")
+        .within("assets")
+        .with_file_system(FailingFileSystem::new(assets(), Failure::TruncatedCopy))
+        .copy_with_options("destination", CopyOptions::new().verify(Checksum::Sha256));
+
+    match result {
+        Err(FileMatcherError::ChecksumMismatch(file, copy)) => {
+            assert_eq!(file, PathBuf::from("assets/cat.txt"));
+            assert_eq!(copy, PathBuf::from("destination/cat.txt"));
+        }
+        other => panic!("Expected a checksum mismatch, got {:?}", other),
+    }
+    Ok(())
+}
+
+#[test]
+pub fn move_truncated_folder_keeps_source() -> Result<()> {
+    let file_system = assets();
+
+    let result = FolderNamed::exact("cat")
+        .within("assets")
+        .with_file_system(FailingFileSystem::new(
+            file_system.clone(),
+            Failure::TruncatedCopy,
+        ))
+        .move_with_options("destination", CopyOptions::new().verify(Checksum::Blake3));
+
+    match result {
+        Err(FileMatcherError::ChecksumMismatch(_, _)) => {}
+        other => panic!("Expected a checksum mismatch, got {:?}", other),
+    }
+    assert_eq!(file_system.read("assets/cat/file.txt")?, b"purr");
+    Ok(())
+}
+
+#[test]
+pub fn move_file_verified() -> Result<()> {
+    let file_system = assets();
+
+    let moved = FileNamed::exact("cat.txt")
+        .within("assets")
+        .with_file_system(file_system.clone())
+        .move_with_options("destination", CopyOptions::new().verify(Checksum::Sha256))?;
+
+    assert_eq!(file_system.read(moved)?, b"meow");
+    assert!(!file_system.contains("assets/cat.txt"));
+    Ok(())
+}
+
+fn cache() -> MemoryFileSystem {
+    MemoryFileSystem::new()
+        .with_file("cache/1f3a.bin", "abc")
+        .with_file("cache/9c2e.bin", "abd")
+        .with_file("cache/nested/77aa.bin", "abc")
+        .with_folder("cache/empty")
+}
+
+#[test]
+pub fn one_entry_hashing() -> Result<()> {
+    let entry = FileNamed::exact("1f3a.bin")
+        .within("cache")
+        .with_file_system(cache());
+
+    assert_eq!(
+        hex(&entry.sha256()?),
+        "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+    );
+    assert_eq!(
+        entry.blake3()?,
+        Checksum::Blake3.of_reader("abc".as_bytes())?
+    );
+    assert_eq!(
+        entry.checksum_hex(Checksum::Blake3)?,
+        "6437b3ac38465133ffb63b75273a8db548c558465d79db03fd359c6cd5bd9d85"
+    );
+    Ok(())
+}
+
+#[test]
+pub fn find_one_file_by_content_hash() -> Result<()> {
+    let file = FileNamed::wildmatch("*.bin")
+        .within("cache")
+        .with_file_system(cache())
+        .with_content_hash(
+            Checksum::Sha256,
+            "BA7816BF8F01CFEA414140DE5DAE2223B00361A396177A9CB410FF61F20015AD",
+        )
+        .find()?;
+
+    assert_eq!(file, PathBuf::from("cache/1f3a.bin"));
+    Ok(())
+}
+
+#[test]
+pub fn find_one_file_by_content_hash_not_exists() -> Result<()> {
+    let result = FileNamed::exact("9c2e.bin")
+        .within("cache")
+        .with_file_system(cache())
+        .with_content_hash(
+            Checksum::Sha256,
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad",
+        )
+        .find_optional()?;
+
+    assert_eq!(result, None);
+    Ok(())
+}
+
+#[test]
+pub fn named_file_with_content_hash() -> Result<()> {
+    let named = FileNamed::wildmatch("*.bin").with_content_hash(
+        Checksum::Sha256,
+        "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad",
+    );
+
+    let file = named.within("cache").with_file_system(cache()).find()?;
+    let alias = named
+        .alias("abc.bin")
+        .within("cache")
+        .with_file_system(cache());
+
+    assert_eq!(file, PathBuf::from("cache/1f3a.bin"));
+    assert_eq!(alias.content_hash(), named.within("cache").content_hash());
+    assert_eq!(alias.find()?, PathBuf::from("cache/1f3a.bin"));
+    Ok(())
+}
+
+#[test]
+pub fn named_files_with_content_hash() -> Result<()> {
+    let files = FilesNamed::wildmatch("*")
+        .with_content_hash(
+            Checksum::Blake3,
+            "6437b3ac38465133ffb63b75273a8db548c558465d79db03fd359c6cd5bd9d85",
+        )
+        .within("cache")
+        .with_file_system(cache())
+        .recursive()
+        .sorted()
+        .find()?;
+
+    assert_eq!(
+        files,
+        vec![
+            PathBuf::from("cache/1f3a.bin"),
+            PathBuf::from("cache/nested/77aa.bin")
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+pub fn find_many_files_by_content_hash() -> Result<()> {
+    let files = FilesNamed::wildmatch("*")
+        .within("cache")
+        .with_file_system(cache())
+        .recursive()
+        .sorted()
+        .with_content_hash(
+            Checksum::Blake3,
+            "6437b3ac38465133ffb63b75273a8db548c558465d79db03fd359c6cd5bd9d85",
+        )
+        .find()?;
+
+    assert_eq!(
+        files,
+        vec![
+            PathBuf::from("cache/1f3a.bin"),
+            PathBuf::from("cache/nested/77aa.bin")
+        ]
+    );
+    Ok(())
+}
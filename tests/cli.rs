@@ -0,0 +1,165 @@
+#![cfg(feature = "cli")]
+
+extern crate tempdir;
+
+use file_matcher::Result;
+use std::process::{Command, Output};
+use tempdir::TempDir;
+
+fn file_matcher(args: &[&str]) -> Result<Output> {
+    Ok(Command::new(env!("CARGO_BIN_EXE_file-matcher"))
+        .args(args)
+        .output()?)
+}
+
+fn stdout(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
+
+#[test]
+pub fn cli_find_file() -> Result<()> {
+    let output = file_matcher(&["find", "--wildmatch", "cat*", "--within", "tests/assets"])?;
+
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(stdout(&output), "tests/assets/cat.txt\n");
+    Ok(())
+}
+
+#[test]
+pub fn cli_find_folder() -> Result<()> {
+    let output = file_matcher(&[
+        "find",
+        "--regex",
+        "^cat",
+        "--type",
+        "folder",
+        "--within",
+        "tests/assets",
+    ])?;
+
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(stdout(&output), "tests/assets/cat\n");
+    Ok(())
+}
+
+#[test]
+pub fn cli_exists() -> Result<()> {
+    let exists = file_matcher(&["exists", "--exact", "cat.txt", "--within", "tests/assets"])?;
+    let missing = file_matcher(&["exists", "--exact", "fox.txt", "--within", "tests/assets"])?;
+    let too_many = file_matcher(&[
+        "exists",
+        "--any",
+        "cat.txt,dog.txt",
+        "--within",
+        "tests/assets",
+    ])?;
+
+    assert_eq!(exists.status.code(), Some(0));
+    assert_eq!(missing.status.code(), Some(3));
+    assert_eq!(too_many.status.code(), Some(4));
+    assert!(missing.stderr.is_empty());
+    assert!(too_many.stderr.is_empty());
+    Ok(())
+}
+
+#[test]
+pub fn cli_exists_reports_failures() -> Result<()> {
+    let output = file_matcher(&["exists", "--regex", "(", "--within", "tests/assets"])?;
+
+    assert_eq!(output.status.code(), Some(1));
+    assert!(!output.stderr.is_empty());
+    Ok(())
+}
+
+#[test]
+pub fn cli_list() -> Result<()> {
+    let output = file_matcher(&["list", "--wildmatch", "*.txt", "--within", "tests/assets"])?;
+
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(
+        stdout(&output),
+        "tests/assets/cat.txt\ntests/assets/dog.txt\n"
+    );
+    Ok(())
+}
+
+#[test]
+pub fn cli_list_wrong_count() -> Result<()> {
+    let not_found = file_matcher(&[
+        "list",
+        "--wildmatch",
+        "*.txt",
+        "--within",
+        "tests/assets",
+        "--at-least",
+        "3",
+    ])?;
+    let too_many = file_matcher(&[
+        "list",
+        "--wildmatch",
+        "*.txt",
+        "--within",
+        "tests/assets",
+        "--at-most",
+        "1",
+    ])?;
+
+    assert_eq!(not_found.status.code(), Some(3));
+    assert_eq!(too_many.status.code(), Some(4));
+    assert!(stdout(&too_many).is_empty());
+    Ok(())
+}
+
+#[test]
+pub fn cli_copy_and_move_with_alias() -> Result<()> {
+    let tmp_dir = TempDir::new("test")?;
+    let destination = tmp_dir.path().join("destination");
+    std::fs::create_dir(&destination)?;
+    let destination = destination.to_str().unwrap();
+
+    let copy = file_matcher(&[
+        "copy",
+        "--exact",
+        "cat.txt",
+        "--within",
+        "tests/assets",
+        "--alias",
+        "kitty.txt",
+        destination,
+    ])?;
+    assert_eq!(copy.status.code(), Some(0));
+    assert_eq!(
+        std::fs::read_to_string(tmp_dir.path().join("destination").join("kitty.txt"))?,
+        std::fs::read_to_string("tests/assets/cat.txt")?
+    );
+
+    let moved = file_matcher(&[
+        "move",
+        "--exact",
+        "kitty.txt",
+        "--within",
+        destination,
+        tmp_dir.path().to_str().unwrap(),
+    ])?;
+    assert_eq!(moved.status.code(), Some(0));
+    assert_eq!(
+        stdout(&moved),
+        format!("{}\n", tmp_dir.path().join("kitty.txt").display())
+    );
+    assert!(!tmp_dir
+        .path()
+        .join("destination")
+        .join("kitty.txt")
+        .exists());
+    Ok(())
+}
+
+#[test]
+pub fn cli_requires_one_name() -> Result<()> {
+    let none = file_matcher(&["find", "--within", "tests/assets"])?;
+    let both = file_matcher(&["find", "--exact", "cat.txt", "--regex", "cat"])?;
+
+    assert_eq!(none.status.code(), Some(2));
+    assert_eq!(both.status.code(), Some(2));
+    Ok(())
+}
@@ -0,0 +1,128 @@
+//! Helpers shared by the integration tests, each test uses only some of them
+#![allow(dead_code)]
+
+use file_matcher::{EntryMetadata, FileSystem, MemoryFileSystem, Result};
+use std::io::{Error, ErrorKind, Read, Write};
+use std::path::{Path, PathBuf};
+
+/// Files and folders most of the tests operate on, with an empty destination folder
+pub fn assets() -> MemoryFileSystem {
+    MemoryFileSystem::new()
+        .with_file("assets/cat.txt", "meow")
+        .with_file("assets/dog.txt", "woof")
+        .with_file("assets/cat/file.txt", "purr")
+        .with_folder("destination")
+}
+
+/// All entries within a folder of a file system
+pub fn entries(file_system: &dyn FileSystem, folder: &Path) -> Result<Vec<PathBuf>> {
+    let mut entries = vec![];
+    for each_entry in file_system.read_dir(folder)? {
+        let each_entry = each_entry?;
+        if file_system.metadata(&each_entry)?.is_folder() {
+            entries.extend(self::entries(file_system, &each_entry)?);
+        }
+        entries.push(each_entry);
+    }
+    entries.sort();
+    Ok(entries)
+}
+
+/// How a [`FailingFileSystem`] fails
+#[derive(Debug, Clone)]
+pub enum Failure {
+    /// Copying a file writes its first byte and fails, like a process killed mid-copy
+    InterruptedCopy,
+    /// Copying a file loses its last byte, like a flaky network mount
+    TruncatedCopy,
+    /// Opening a file with a given name fails
+    UnreadableFile(&'static str),
+    /// Removing a given folder removes a given file within it and fails
+    PartialRemoval {
+        folder: &'static str,
+        removed: &'static str,
+    },
+}
+
+/// A memory file system that fails in a given way to test how the actions handle failures
+#[derive(Debug)]
+pub struct FailingFileSystem {
+    file_system: MemoryFileSystem,
+    failure: Failure,
+}
+
+impl FailingFileSystem {
+    pub fn new(file_system: MemoryFileSystem, failure: Failure) -> Self {
+        Self {
+            file_system,
+            failure,
+        }
+    }
+}
+
+impl FileSystem for FailingFileSystem {
+    fn metadata(&self, path: &Path) -> std::io::Result<EntryMetadata> {
+        self.file_system.metadata(path)
+    }
+
+    fn read_dir(
+        &self,
+        path: &Path,
+    ) -> std::io::Result<Box<dyn Iterator<Item = std::io::Result<PathBuf>>>> {
+        self.file_system.read_dir(path)
+    }
+
+    fn open(&self, path: &Path) -> std::io::Result<Box<dyn Read>> {
+        match self.failure {
+            Failure::UnreadableFile(file_name) if path.ends_with(file_name) => {
+                Err(Error::from(ErrorKind::PermissionDenied))
+            }
+            _ => self.file_system.open(path),
+        }
+    }
+
+    fn create(&self, path: &Path) -> std::io::Result<Box<dyn Write>> {
+        self.file_system.create(path)
+    }
+
+    fn create_dir_all(&self, path: &Path) -> std::io::Result<()> {
+        self.file_system.create_dir_all(path)
+    }
+
+    fn remove_file(&self, path: &Path) -> std::io::Result<()> {
+        self.file_system.remove_file(path)
+    }
+
+    fn remove_dir_all(&self, path: &Path) -> std::io::Result<()> {
+        match self.failure {
+            Failure::PartialRemoval { folder, removed } if path == Path::new(folder) => {
+                self.file_system.remove_file(&path.join(removed))?;
+                Err(Error::from(ErrorKind::PermissionDenied))
+            }
+            _ => self.file_system.remove_dir_all(path),
+        }
+    }
+
+    fn rename(&self, from: &Path, to: &Path) -> std::io::Result<()> {
+        self.file_system.rename(from, to)
+    }
+
+    fn copy_file(&self, from: &Path, to: &Path) -> std::io::Result<u64> {
+        let mut contents = self.file_system.read(from)?;
+        match self.failure {
+            Failure::InterruptedCopy => {
+                self.file_system.create(to)?.write_all(&contents[..1])?;
+                Err(Error::from(ErrorKind::Interrupted))
+            }
+            Failure::TruncatedCopy => {
+                contents.pop();
+                self.file_system.create(to)?.write_all(&contents)?;
+                Ok(contents.len() as u64)
+            }
+            _ => {
+                self.file_system.create(to)?.write_all(&contents)?;
+                Ok(contents.len() as u64)
+            }
+        }
+    }
+}
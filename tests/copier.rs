@@ -66,3 +66,42 @@ pub fn copy_one_folder_exact_alias() -> Result<()> {
     tmp_dir.close()?;
     Ok(())
 }
+
+#[cfg(unix)]
+#[test]
+pub fn copy_one_file_into_symlink_to_folder() -> Result<()> {
+    let tmp_dir = TempDir::new("test")?;
+    let folder = tmp_dir.path().join("folder");
+    let link = tmp_dir.path().join("link");
+    std::fs::create_dir(&folder)?;
+    std::os::unix::fs::symlink(&folder, &link)?;
+
+    let copied = FileNamed::exact("cat.txt")
+        .within("tests/assets")
+        .copy(&link)?;
+
+    assert_eq!(copied, link.join("cat.txt"));
+    assert!(folder.join("cat.txt").is_file());
+    Ok(())
+}
+
+#[cfg(all(unix, feature = "mover"))]
+#[test]
+pub fn move_one_file_into_symlink_to_folder() -> Result<()> {
+    use file_matcher::OneEntryMover;
+
+    let tmp_dir = TempDir::new("test")?;
+    let folder = tmp_dir.path().join("folder");
+    let link = tmp_dir.path().join("link");
+    std::fs::create_dir(&folder)?;
+    std::os::unix::fs::symlink(&folder, &link)?;
+    std::fs::write(tmp_dir.path().join("cat.txt"), "meow")?;
+
+    let moved = FileNamed::exact("cat.txt")
+        .within(tmp_dir.path())
+        .r#move(&link)?;
+
+    assert_eq!(moved, link.join("cat.txt"));
+    assert_eq!(std::fs::read_to_string(folder.join("cat.txt"))?, "meow");
+    Ok(())
+}
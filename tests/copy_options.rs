@@ -0,0 +1,153 @@
+#![cfg(feature = "copier")]
+
+extern crate tempdir;
+
+use file_matcher::{
+    CopyOptions, FileNamed, FileSystem, FilesNamed, FolderNamed, ManyEntriesCopier,
+    MemoryFileSystem, OneEntryCopier, Result,
+};
+use std::fs::File;
+use std::path::Path;
+use std::time::{Duration, SystemTime};
+use tempdir::TempDir;
+
+fn long_ago() -> SystemTime {
+    SystemTime::UNIX_EPOCH + Duration::from_secs(1_500_000_000)
+}
+
+fn set_modified(path: &Path, modified: SystemTime) -> Result<()> {
+    if path.is_dir() {
+        File::open(path)?.set_modified(modified)?;
+    } else {
+        File::options()
+            .write(true)
+            .open(path)?
+            .set_modified(modified)?;
+    }
+    Ok(())
+}
+
+fn modified(path: &Path) -> Result<SystemTime> {
+    Ok(std::fs::metadata(path)?.modified()?)
+}
+
+#[test]
+pub fn copy_file_preserving_times() -> Result<()> {
+    let tmp_dir = TempDir::new("test")?;
+    let file = tmp_dir.path().join("cat.txt");
+    std::fs::write(&file, "meow")?;
+    set_modified(&file, long_ago())?;
+
+    let copy = FileNamed::exact("cat.txt")
+        .alias("kitty.txt")
+        .within(tmp_dir.path())
+        .copy_with_options(tmp_dir.path(), CopyOptions::new().preserve_times())?;
+
+    assert_eq!(modified(&copy)?, long_ago());
+    Ok(())
+}
+
+#[test]
+pub fn copy_file_without_options_changes_times() -> Result<()> {
+    let tmp_dir = TempDir::new("test")?;
+    let file = tmp_dir.path().join("cat.txt");
+    std::fs::write(&file, "meow")?;
+    set_modified(&file, long_ago())?;
+
+    let copy = FileNamed::exact("cat.txt")
+        .alias("kitty.txt")
+        .within(tmp_dir.path())
+        .copy_with_options(tmp_dir.path(), CopyOptions::new())?;
+
+    assert_ne!(modified(&copy)?, long_ago());
+    Ok(())
+}
+
+#[cfg(unix)]
+#[test]
+pub fn copy_folder_preserving_all() -> Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let tmp_dir = TempDir::new("test")?;
+    let folder = tmp_dir.path().join("source").join("bin");
+    std::fs::create_dir_all(&folder)?;
+    let tool = folder.join("tool");
+    std::fs::write(&tool, "#!/bin/sh")?;
+    std::fs::set_permissions(&tool, std::fs::Permissions::from_mode(0o750))?;
+    std::fs::set_permissions(&folder, std::fs::Permissions::from_mode(0o700))?;
+    set_modified(&tool, long_ago())?;
+    set_modified(&folder, long_ago())?;
+
+    let destination = tmp_dir.path().join("destination");
+    std::fs::create_dir(&destination)?;
+
+    let copy = FolderNamed::exact("bin")
+        .within(tmp_dir.path().join("source"))
+        .copy_with_options(&destination, CopyOptions::preserve_all())?;
+
+    assert_eq!(copy, destination.join("bin"));
+    assert_eq!(modified(&copy)?, long_ago());
+    assert_eq!(modified(&copy.join("tool"))?, long_ago());
+    assert_eq!(
+        std::fs::metadata(&copy)?.permissions().mode() & 0o7777,
+        0o700
+    );
+    assert_eq!(
+        std::fs::metadata(copy.join("tool"))?.permissions().mode() & 0o7777,
+        0o750
+    );
+    Ok(())
+}
+
+#[cfg(all(unix, feature = "xattr"))]
+#[test]
+pub fn copy_file_preserving_xattrs() -> Result<()> {
+    let tmp_dir = TempDir::new("test")?;
+    let file = tmp_dir.path().join("cat.txt");
+    std::fs::write(&file, "meow")?;
+
+    // not every file system supports user extended attributes
+    if file_matcher::StdFileSystem
+        .set_xattr(&file, "user.sound".as_ref(), b"meow")
+        .is_err()
+    {
+        return Ok(());
+    }
+
+    let copy = FileNamed::exact("cat.txt")
+        .alias("kitty.txt")
+        .within(tmp_dir.path())
+        .copy_with_options(tmp_dir.path(), CopyOptions::new().preserve_xattrs())?;
+
+    assert_eq!(
+        file_matcher::StdFileSystem.xattrs(&copy)?,
+        vec![("user.sound".into(), b"meow".to_vec())]
+    );
+    Ok(())
+}
+
+#[test]
+pub fn copy_many_preserving_times_in_memory() -> Result<()> {
+    let file_system = MemoryFileSystem::new()
+        .with_file("assets/cat.txt", "meow")
+        .with_file("assets/dog.txt", "woof");
+    file_system.set_times(Path::new("assets/cat.txt"), None, Some(long_ago()))?;
+
+    let copies = FilesNamed::wildmatch("*.txt")
+        .within("assets")
+        .with_file_system(file_system.clone())
+        .sorted()
+        .copy_with_options("copies", CopyOptions::new().preserve_times())?;
+
+    assert_eq!(
+        file_system.metadata(&copies[0])?.modified(),
+        Some(long_ago())
+    );
+    assert_eq!(
+        file_system.metadata(&copies[1])?.modified(),
+        file_system
+            .metadata(Path::new("assets/dog.txt"))?
+            .modified()
+    );
+    Ok(())
+}
@@ -0,0 +1,98 @@
+use file_matcher::{
+    DiffComparison, FileMatcherError, FileSystem, FilesNamed, MemoryFileSystem, Result,
+};
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime};
+
+fn builds() -> MemoryFileSystem {
+    MemoryFileSystem::new()
+        .with_file("old/libcat.so", "meow")
+        .with_file("old/libdog.so", "woof")
+        .with_file("old/plugins/libbird.so", "tweet")
+        .with_file("old/readme.txt", "old")
+        .with_file("new/libcat.so", "purr")
+        .with_file("new/libdog.so", "woof!")
+        .with_file("new/plugins/libfish.so", "blub")
+        .with_file("new/readme.txt", "new readme")
+}
+
+fn paths(paths: &[&str]) -> Vec<PathBuf> {
+    paths.iter().map(PathBuf::from).collect()
+}
+
+#[test]
+pub fn diff_by_size() -> Result<()> {
+    let diff = FilesNamed::wildmatch("*.so")
+        .within("old")
+        .with_file_system(builds())
+        .recursive()
+        .diff("new")?;
+
+    assert_eq!(diff.added(), paths(&["plugins/libfish.so"]));
+    assert_eq!(diff.removed(), paths(&["plugins/libbird.so"]));
+    assert_eq!(diff.changed(), paths(&["libdog.so"]));
+    assert!(!diff.is_empty());
+    Ok(())
+}
+
+#[test]
+pub fn diff_by_modification_time() -> Result<()> {
+    let file_system = builds();
+    let long_ago = SystemTime::UNIX_EPOCH + Duration::from_secs(1_500_000_000);
+    file_system.set_times("old/libcat.so".as_ref(), None, Some(long_ago))?;
+
+    let diff = FilesNamed::exact("libcat.so")
+        .within("old")
+        .with_file_system(file_system)
+        .diff_by("new", DiffComparison::Modified)?;
+
+    assert_eq!(diff.changed(), paths(&["libcat.so"]));
+    Ok(())
+}
+
+#[cfg(feature = "blake3")]
+#[test]
+pub fn diff_by_checksum() -> Result<()> {
+    use file_matcher::Checksum;
+
+    let diff = FilesNamed::wildmatch("*.so")
+        .within("old")
+        .with_file_system(builds())
+        .diff_by("new", DiffComparison::Checksum(Checksum::Blake3))?;
+
+    assert!(diff.added().is_empty());
+    assert!(diff.removed().is_empty());
+    assert_eq!(diff.changed(), paths(&["libcat.so", "libdog.so"]));
+    Ok(())
+}
+
+#[test]
+pub fn diff_of_same_folder_is_empty() -> Result<()> {
+    let diff = FilesNamed::wildmatch("*")
+        .within("old")
+        .with_file_system(builds())
+        .recursive()
+        .diff_by("old", DiffComparison::SizeAndModified)?;
+
+    assert!(diff.is_empty());
+    Ok(())
+}
+
+#[test]
+pub fn diff_checks_count_within_both_folders() -> Result<()> {
+    let result = FilesNamed::wildmatch("*.so")
+        .within("old")
+        .with_file_system(builds())
+        .recursive()
+        .exactly(3)
+        .diff("new/plugins");
+
+    match result {
+        Err(FileMatcherError::WrongCount(entries, found)) => {
+            assert_eq!(entries.directory(), PathBuf::from("new/plugins"));
+            assert_eq!(found, paths(&["new/plugins/libfish.so"]));
+        }
+        other => panic!("Expected a wrong count, got {:?}", other),
+    }
+    Ok(())
+}
@@ -0,0 +1,121 @@
+#![cfg(all(feature = "copier", feature = "mover"))]
+
+mod common;
+
+use common::assets;
+use file_matcher::{
+    FileNamed, FilesNamed, FolderNamed, ManyEntriesCopier, OneEntryCopier, OneEntryMover,
+    Operation, Plan, Result,
+};
+use std::path::PathBuf;
+
+#[test]
+pub fn copy_file_dry_run_with_alias() -> Result<()> {
+    let file_system = assets();
+
+    let plan = FileNamed::wildmatch("cat*")
+        .alias("kitty.txt")
+        .within("assets")
+        .with_file_system(file_system.clone())
+        .copy_dry_run("destination")?;
+
+    assert_eq!(
+        plan.operations(),
+        &[Operation::CopyFile {
+            from: PathBuf::from("assets/cat.txt"),
+            to: PathBuf::from("destination/kitty.txt"),
+        }]
+    );
+    assert!(!file_system.contains("destination/kitty.txt"));
+    Ok(())
+}
+
+#[test]
+pub fn copy_file_dry_run_to_path() -> Result<()> {
+    let plan = FileNamed::exact("cat.txt")
+        .within("assets")
+        .with_file_system(assets())
+        .copy_dry_run("destination/kitty.txt")?;
+
+    assert_eq!(
+        plan.operations(),
+        &[Operation::CopyFile {
+            from: PathBuf::from("assets/cat.txt"),
+            to: PathBuf::from("destination/kitty.txt"),
+        }]
+    );
+    Ok(())
+}
+
+#[test]
+pub fn copy_many_dry_run() -> Result<()> {
+    let file_system = assets();
+
+    let plan = FilesNamed::wildmatch("*.txt")
+        .within("assets")
+        .with_file_system(file_system.clone())
+        .sorted()
+        .copy_dry_run("copies")?;
+
+    assert_eq!(
+        plan,
+        Plan::from(vec![
+            Operation::CreateFolder(PathBuf::from("copies")),
+            Operation::CopyFile {
+                from: PathBuf::from("assets/cat.txt"),
+                to: PathBuf::from("copies/cat.txt"),
+            },
+            Operation::CopyFile {
+                from: PathBuf::from("assets/dog.txt"),
+                to: PathBuf::from("copies/dog.txt"),
+            },
+        ])
+    );
+    assert!(!file_system.contains("copies"));
+    Ok(())
+}
+
+#[test]
+pub fn move_folder_dry_run() -> Result<()> {
+    let file_system = assets();
+
+    let plan = FolderNamed::exact("cat")
+        .alias("kitty")
+        .within("assets")
+        .with_file_system(file_system.clone())
+        .move_dry_run("destination")?;
+
+    assert_eq!(
+        plan.operations(),
+        &[Operation::MoveFolder {
+            from: PathBuf::from("assets/cat"),
+            to: PathBuf::from("destination/kitty"),
+        }]
+    );
+    assert!(file_system.contains("assets/cat/file.txt"));
+    Ok(())
+}
+
+#[test]
+pub fn dry_run_plan_display() -> Result<()> {
+    let plan = FileNamed::exact("cat.txt")
+        .within("assets")
+        .with_file_system(assets())
+        .move_dry_run("destination")?;
+
+    assert_eq!(
+        plan.to_string(),
+        "move file \"assets/cat.txt\" to \"destination/cat.txt\"\n"
+    );
+    Ok(())
+}
+
+#[test]
+pub fn dry_run_fails_when_not_found() {
+    let result = FileNamed::exact("bird.txt")
+        .within("assets")
+        .with_file_system(assets())
+        .copy_dry_run("destination");
+
+    assert!(result.is_err());
+}
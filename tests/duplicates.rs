@@ -0,0 +1,106 @@
+#![cfg(feature = "sha256")]
+
+extern crate tempdir;
+
+use file_matcher::{Checksum, FilesNamed, MemoryFileSystem, Result};
+use std::path::PathBuf;
+use tempdir::TempDir;
+
+fn bundle() -> MemoryFileSystem {
+    MemoryFileSystem::new()
+        .with_file("bundle/libcat.so", "meow")
+        .with_file("bundle/libcat.so.1", "meow")
+        .with_file("bundle/plugins/libkitty.so", "meow")
+        .with_file("bundle/libdog.so", "woof")
+        .with_file("bundle/libpuppy.so", "woof")
+        .with_file("bundle/libfox.so", "yelp")
+        .with_file("bundle/empty.so", "")
+        .with_file("bundle/empty.so.1", "")
+}
+
+fn paths(paths: &[&str]) -> Vec<PathBuf> {
+    paths.iter().map(PathBuf::from).collect()
+}
+
+#[test]
+pub fn find_duplicates() -> Result<()> {
+    let duplicates = FilesNamed::wildmatch("*.so*")
+        .within("bundle")
+        .with_file_system(bundle())
+        .recursive()
+        .duplicates(Checksum::Sha256)?;
+
+    assert_eq!(duplicates.len(), 2);
+
+    assert_eq!(duplicates[0].size(), 4);
+    assert_eq!(
+        duplicates[0].files(),
+        paths(&[
+            "bundle/libcat.so",
+            "bundle/libcat.so.1",
+            "bundle/plugins/libkitty.so"
+        ])
+    );
+    assert_eq!(duplicates[0].original(), PathBuf::from("bundle/libcat.so"));
+    assert_eq!(
+        duplicates[0].duplicates(),
+        paths(&["bundle/libcat.so.1", "bundle/plugins/libkitty.so"])
+    );
+
+    assert_eq!(
+        duplicates[1].files(),
+        paths(&["bundle/libdog.so", "bundle/libpuppy.so"])
+    );
+    Ok(())
+}
+
+#[test]
+pub fn no_duplicates() -> Result<()> {
+    let duplicates = FilesNamed::wildmatch("libfox.so")
+        .within("bundle")
+        .with_file_system(bundle())
+        .duplicates(Checksum::Sha256)?;
+
+    assert!(duplicates.is_empty());
+    Ok(())
+}
+
+#[cfg(feature = "linker")]
+#[test]
+pub fn link_duplicates() -> Result<()> {
+    let tmp_dir = TempDir::new("test")?;
+    std::fs::write(tmp_dir.path().join("libcat.so"), "meow")?;
+    std::fs::write(tmp_dir.path().join("libkitty.so"), "meow")?;
+    std::fs::write(tmp_dir.path().join("libdog.so"), "woof")?;
+
+    let libraries = FilesNamed::wildmatch("*.so").within(tmp_dir.path());
+    let linked = libraries.link_duplicates(Checksum::Sha256)?;
+
+    assert_eq!(linked.len(), 1);
+    assert_eq!(
+        linked[0].duplicates(),
+        vec![tmp_dir.path().join("libkitty.so")]
+    );
+    assert_eq!(std::fs::read_dir(tmp_dir.path())?.count(), 3);
+
+    // a change of the original is visible through its hard link
+    std::fs::write(tmp_dir.path().join("libcat.so"), "purr")?;
+    assert_eq!(std::fs::read(tmp_dir.path().join("libkitty.so"))?, b"purr");
+    assert_eq!(std::fs::read(tmp_dir.path().join("libdog.so"))?, b"woof");
+    Ok(())
+}
+
+#[cfg(feature = "linker")]
+#[test]
+pub fn link_duplicates_unsupported_by_file_system() -> Result<()> {
+    let file_system = bundle();
+
+    let result = FilesNamed::wildmatch("lib*.so")
+        .within("bundle")
+        .with_file_system(file_system.clone())
+        .link_duplicates(Checksum::Sha256);
+
+    assert!(result.is_err());
+    assert_eq!(file_system.read("bundle/libpuppy.so")?, b"woof");
+    Ok(())
+}
@@ -0,0 +1,211 @@
+#![cfg(all(feature = "linker", unix))]
+
+extern crate tempdir;
+
+use file_matcher::{
+    FileNamed, FilesNamed, LinkKind, ManyEntriesLinker, MemoryFileSystem, OneEntryLinker,
+    Operation, OverwritePolicy, Result,
+};
+use std::path::{Path, PathBuf};
+use tempdir::TempDir;
+
+fn library(folder: &Path) -> Result<PathBuf> {
+    let lib = folder.join("lib");
+    std::fs::create_dir(&lib)?;
+    std::fs::write(lib.join("libfoo.so.1"), "foo")?;
+    std::fs::write(lib.join("libbar.so.2"), "bar")?;
+    Ok(lib)
+}
+
+#[test]
+pub fn symlink_with_alias_next_to_file() -> Result<()> {
+    let tmp_dir = TempDir::new("test").unwrap();
+    let lib = library(tmp_dir.path())?;
+
+    let link = FileNamed::wildmatch("libfoo.so.*")
+        .alias("libfoo.so")
+        .within(&lib)
+        .symlink(&lib)?;
+
+    assert_eq!(link, lib.join("libfoo.so"));
+    assert_eq!(std::fs::read_link(&link)?, PathBuf::from("libfoo.so.1"));
+    assert_eq!(std::fs::read_to_string(&link)?, "foo");
+    Ok(())
+}
+
+#[test]
+pub fn symlink_into_symlink_to_folder() -> Result<()> {
+    let tmp_dir = TempDir::new("test").unwrap();
+    let lib = library(tmp_dir.path())?;
+    let bin = tmp_dir.path().join("bin");
+    std::fs::create_dir(&bin)?;
+    std::os::unix::fs::symlink(&bin, tmp_dir.path().join("bin-link"))?;
+
+    let link = FileNamed::exact("libfoo.so.1")
+        .within(&lib)
+        .symlink(tmp_dir.path().join("bin-link"))?;
+
+    assert_eq!(link, tmp_dir.path().join("bin-link").join("libfoo.so.1"));
+    assert_eq!(std::fs::read_to_string(bin.join("libfoo.so.1"))?, "foo");
+    Ok(())
+}
+
+#[test]
+pub fn symlink_into_another_folder_is_relative() -> Result<()> {
+    let tmp_dir = TempDir::new("test").unwrap();
+    let lib = library(tmp_dir.path())?;
+    let bin = tmp_dir.path().join("bin");
+    std::fs::create_dir(&bin)?;
+
+    let link = FileNamed::exact("libfoo.so.1").within(&lib).symlink(&bin)?;
+
+    assert_eq!(link, bin.join("libfoo.so.1"));
+    assert_eq!(
+        std::fs::read_link(&link)?,
+        Path::new("..").join("lib").join("libfoo.so.1")
+    );
+    Ok(())
+}
+
+#[test]
+pub fn hard_link_to_path() -> Result<()> {
+    let tmp_dir = TempDir::new("test").unwrap();
+    let lib = library(tmp_dir.path())?;
+
+    let link = FileNamed::exact("libfoo.so.1")
+        .within(&lib)
+        .hard_link(tmp_dir.path().join("foo"))?;
+
+    assert_eq!(link, tmp_dir.path().join("foo"));
+    assert!(std::fs::symlink_metadata(&link)?.file_type().is_file());
+    assert_eq!(std::fs::read_to_string(&link)?, "foo");
+    Ok(())
+}
+
+#[test]
+pub fn link_overwrite_policy() -> Result<()> {
+    let tmp_dir = TempDir::new("test").unwrap();
+    let lib = library(tmp_dir.path())?;
+    std::fs::write(lib.join("libfoo.so"), "old")?;
+
+    let entry = FileNamed::exact("libfoo.so.1")
+        .alias("libfoo.so")
+        .within(&lib);
+
+    assert!(entry.symlink(&lib).is_err());
+
+    entry.link(&lib, LinkKind::Symbolic, OverwritePolicy::Skip)?;
+    assert_eq!(std::fs::read_to_string(lib.join("libfoo.so"))?, "old");
+
+    entry.link(&lib, LinkKind::Symbolic, OverwritePolicy::Replace)?;
+    assert_eq!(std::fs::read_to_string(lib.join("libfoo.so"))?, "foo");
+    Ok(())
+}
+
+#[test]
+pub fn link_dry_run_replace() -> Result<()> {
+    let tmp_dir = TempDir::new("test").unwrap();
+    let lib = library(tmp_dir.path())?;
+    std::fs::write(lib.join("libfoo.so"), "old")?;
+
+    let plan = FileNamed::exact("libfoo.so.1")
+        .alias("libfoo.so")
+        .within(&lib)
+        .link_dry_run(&lib, LinkKind::Symbolic, OverwritePolicy::Replace)?;
+
+    assert_eq!(
+        plan.operations(),
+        &[
+            Operation::RemoveFile(lib.join("libfoo.so")),
+            Operation::Symlink {
+                target: PathBuf::from("libfoo.so.1"),
+                link: lib.join("libfoo.so"),
+            },
+        ]
+    );
+    assert_eq!(std::fs::read_to_string(lib.join("libfoo.so"))?, "old");
+    Ok(())
+}
+
+#[test]
+pub fn symlink_many_files() -> Result<()> {
+    let tmp_dir = TempDir::new("test").unwrap();
+    let lib = library(tmp_dir.path())?;
+    let links = tmp_dir.path().join("links");
+
+    let mut created = FilesNamed::wildmatch("*.so.*")
+        .within(&lib)
+        .symlink(&links)?;
+    created.sort();
+
+    assert_eq!(
+        created,
+        vec![links.join("libbar.so.2"), links.join("libfoo.so.1")]
+    );
+    assert_eq!(std::fs::read_to_string(links.join("libbar.so.2"))?, "bar");
+    Ok(())
+}
+
+#[test]
+pub fn link_many_files_returns_only_created_links() -> Result<()> {
+    let tmp_dir = TempDir::new("test").unwrap();
+    let lib = library(tmp_dir.path())?;
+    let links = tmp_dir.path().join("links");
+    std::fs::create_dir(&links)?;
+    std::fs::write(links.join("libbar.so.2"), "old")?;
+
+    let created = FilesNamed::wildmatch("*.so.*").within(&lib).link(
+        &links,
+        LinkKind::Symbolic,
+        OverwritePolicy::Skip,
+    )?;
+
+    assert_eq!(created, vec![links.join("libfoo.so.1")]);
+    assert_eq!(std::fs::read_to_string(links.join("libbar.so.2"))?, "old");
+    Ok(())
+}
+
+#[test]
+pub fn symlink_many_nested_files_with_same_name() -> Result<()> {
+    let tmp_dir = TempDir::new("test").unwrap();
+    let lib = tmp_dir.path().join("lib");
+    std::fs::create_dir_all(lib.join("a"))?;
+    std::fs::create_dir_all(lib.join("b"))?;
+    std::fs::write(lib.join("a").join("libfoo.so"), "a")?;
+    std::fs::write(lib.join("b").join("libfoo.so"), "b")?;
+    let links = tmp_dir.path().join("links");
+
+    let created = FilesNamed::exact("libfoo.so")
+        .within(&lib)
+        .recursive()
+        .sorted()
+        .symlink(&links)?;
+
+    assert_eq!(
+        created,
+        vec![
+            links.join("a").join("libfoo.so"),
+            links.join("b").join("libfoo.so")
+        ]
+    );
+    assert_eq!(
+        std::fs::read_link(links.join("a").join("libfoo.so"))?,
+        Path::new("../../lib/a/libfoo.so")
+    );
+    assert_eq!(
+        std::fs::read_to_string(links.join("b").join("libfoo.so"))?,
+        "b"
+    );
+    Ok(())
+}
+
+#[test]
+pub fn symlink_unsupported_in_memory() -> Result<()> {
+    let result = FileNamed::exact("cat.txt")
+        .within("assets")
+        .with_file_system(MemoryFileSystem::new().with_file("assets/cat.txt", "meow"))
+        .symlink("assets/kitty.txt");
+
+    assert!(result.is_err());
+    Ok(())
+}
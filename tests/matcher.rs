@@ -2,6 +2,7 @@ use file_matcher::{
     FileMatcherError, FileNamed, FileOrFolderNamed, FilesNamed, FolderNamed, Result,
 };
 use std::ffi::OsStr;
+use std::path::{Path, PathBuf};
 
 #[test]
 pub fn find_one_file_exact() -> Result<()> {
@@ -28,6 +29,29 @@ pub fn does_not_exist_one_file_exact() -> Result<()> {
     Ok(())
 }
 
+#[test]
+pub fn find_optional_one_file_exact() -> Result<()> {
+    let file = FileNamed::exact("cat.txt")
+        .within("tests/assets")
+        .find_optional()?;
+    assert_eq!(file, Some(PathBuf::from("tests/assets/cat.txt")));
+
+    let file = FileNamed::exact("kitty.txt")
+        .within("tests/assets")
+        .find_optional()?;
+    assert_eq!(file, None);
+    Ok(())
+}
+
+#[test]
+pub fn find_optional_too_many() {
+    let entry = FileOrFolderNamed::wildmatch("cat*").within("tests/assets");
+    match entry.find_optional() {
+        Err(FileMatcherError::TooMany(_)) => {}
+        other => panic!("Should fail with too many, but got {:?}", other),
+    }
+}
+
 #[test]
 pub fn one_folder_exact() -> Result<()> {
     let folder = FolderNamed::exact("cat").within("tests/assets").find()?;
@@ -155,3 +179,252 @@ pub fn many_wildmatch() -> Result<()> {
     assert!(file_names.contains(&OsStr::new("dog.txt")));
     Ok(())
 }
+
+#[test]
+pub fn many_iter_take() -> Result<()> {
+    let files = FilesNamed::wildmatch("*.txt")
+        .within("tests/assets")
+        .iter()
+        .take(1)
+        .collect::<Result<Vec<PathBuf>>>()?;
+
+    assert_eq!(files.len(), 1);
+    Ok(())
+}
+
+#[test]
+pub fn many_into_iter() -> Result<()> {
+    let mut file_names = vec![];
+    for file in FilesNamed::any(vec!["cat.txt", "dog.txt", "bird.txt"]).within("tests/assets") {
+        file_names.push(file?.file_name().unwrap().to_os_string());
+    }
+
+    assert_eq!(file_names.len(), 2);
+    assert!(file_names.contains(&OsStr::new("cat.txt").to_os_string()));
+    assert!(file_names.contains(&OsStr::new("dog.txt").to_os_string()));
+    Ok(())
+}
+
+#[test]
+pub fn many_iter_invalid_regex() {
+    let mut files = FilesNamed::regex("cat(").within("tests/assets").iter();
+
+    match files.next() {
+        Some(Err(FileMatcherError::RegexError(_))) => {}
+        other => panic!("Should fail with a regex error, but got {:?}", other),
+    }
+    assert!(files.next().is_none());
+}
+
+#[test]
+pub fn many_recursive_exact() -> Result<()> {
+    let files = FilesNamed::exact("file.txt")
+        .within("tests/assets")
+        .recursive()
+        .find()?;
+
+    assert_eq!(files, vec![Path::new("tests/assets/cat/file.txt")]);
+    Ok(())
+}
+
+#[test]
+pub fn many_recursive_wildmatch_sorted() -> Result<()> {
+    let files = FilesNamed::wildmatch("*.txt")
+        .within("tests/assets")
+        .recursive()
+        .sorted()
+        .find()?;
+
+    assert_eq!(
+        files,
+        vec![
+            Path::new("tests/assets/cat/file.txt"),
+            Path::new("tests/assets/cat.txt"),
+            Path::new("tests/assets/dog.txt"),
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+pub fn many_nested_names_with_and_without_recursion() -> Result<()> {
+    let files = FilesNamed::any(vec!["cat/file.txt", "dog.txt"]).within("tests/assets");
+
+    let found = files.clone().sorted().find()?;
+    let found_recursively = files.clone().recursive().sorted().find()?;
+
+    assert_eq!(
+        found,
+        vec![
+            Path::new("tests/assets/cat/file.txt"),
+            Path::new("tests/assets/dog.txt"),
+        ]
+    );
+    assert_eq!(found_recursively, found);
+    #[cfg(feature = "parallel")]
+    assert_eq!(files.recursive().parallel().sorted().find()?, found);
+    Ok(())
+}
+
+#[test]
+pub fn many_nested_name_matches_relative_to_search_folder() -> Result<()> {
+    let files = FilesNamed::exact("cat/file.txt")
+        .within("tests")
+        .recursive()
+        .find()?;
+
+    assert!(files.is_empty());
+    Ok(())
+}
+
+#[cfg(feature = "parallel")]
+#[test]
+pub fn many_parallel_recursive_wildmatch_sorted() -> Result<()> {
+    let files = FilesNamed::wildmatch("*.txt")
+        .within("tests/assets")
+        .recursive()
+        .parallel()
+        .sorted()
+        .find()?;
+
+    assert_eq!(
+        files,
+        vec![
+            Path::new("tests/assets/cat/file.txt"),
+            Path::new("tests/assets/cat.txt"),
+            Path::new("tests/assets/dog.txt"),
+        ]
+    );
+    Ok(())
+}
+
+#[cfg(feature = "parallel")]
+#[test]
+pub fn many_parallel_regex() -> Result<()> {
+    let files = FilesNamed::regex(".*\\.txt")
+        .within("tests/assets")
+        .parallel()
+        .find()?;
+
+    assert_eq!(files.len(), 2);
+    assert!(files.contains(&PathBuf::from("tests/assets/cat.txt")));
+    assert!(files.contains(&PathBuf::from("tests/assets/dog.txt")));
+    Ok(())
+}
+
+#[test]
+pub fn many_exactly() -> Result<()> {
+    let files = FilesNamed::wildmatch("*.txt")
+        .within("tests/assets")
+        .exactly(2)
+        .find()?;
+
+    assert_eq!(files.len(), 2);
+    Ok(())
+}
+
+#[test]
+pub fn many_later_bound_moves_the_other() -> Result<()> {
+    let at_most = FilesNamed::wildmatch("*.txt")
+        .within("tests/assets")
+        .at_least(5)
+        .at_most(2);
+    assert_eq!(at_most.count().min(), 2);
+    assert_eq!(at_most.count().max(), Some(2));
+    assert_eq!(at_most.count().to_string(), "exactly 2");
+    assert_eq!(at_most.find()?.len(), 2);
+
+    let at_least = FilesNamed::wildmatch("*.txt")
+        .within("tests/assets")
+        .at_most(1)
+        .at_least(2);
+    assert_eq!(at_least.count().min(), 2);
+    assert_eq!(at_least.count().max(), Some(2));
+    assert_eq!(at_least.find()?.len(), 2);
+    Ok(())
+}
+
+#[test]
+pub fn many_at_least_error() -> Result<()> {
+    let entries = FilesNamed::wildmatch("*.txt")
+        .within("tests/assets")
+        .at_least(3)
+        .at_most(4);
+
+    match entries.find() {
+        Ok(files) => {
+            panic!("Should fail, but found {:?}", &files)
+        }
+        Err(error) => match &error {
+            FileMatcherError::WrongCount(failed_entries, found) => {
+                assert_eq!(failed_entries.count().min(), 3);
+                assert_eq!(failed_entries.count().max(), Some(4));
+                assert_eq!(found.len(), 2);
+                assert_eq!(
+                    error.to_string(),
+                    format!(
+                        "Expected between 3 and 4 File named Wildmatch(\"*.txt\") in \"tests/assets\", but found 2: {:?}",
+                        found
+                    )
+                );
+            }
+            _ => {
+                panic!("Wrong error type {:?}", error);
+            }
+        },
+    }
+    Ok(())
+}
+
+#[test]
+pub fn many_non_empty_error() -> Result<()> {
+    let entries = FilesNamed::exact("bird.txt")
+        .within("tests/assets")
+        .non_empty();
+
+    match entries.find() {
+        Err(FileMatcherError::WrongCount(_, found)) => assert!(found.is_empty()),
+        other => panic!("Should fail with a wrong count, but got {:?}", other),
+    }
+    Ok(())
+}
+
+/// Without the async feature the named entries do not have to be thread-safe
+#[cfg(not(feature = "async"))]
+#[test]
+pub fn named_entries_without_thread_safety() -> Result<()> {
+    use file_matcher::{EntryName, EntryType, OneEntry, OneEntryNamed};
+    use std::rc::Rc;
+
+    #[derive(Debug, Clone)]
+    struct SharedName(Rc<EntryName>);
+
+    impl OneEntryNamed for SharedName {
+        fn within_path_buf(&self, directory: PathBuf) -> OneEntry {
+            OneEntry::new(self.boxed(), directory)
+        }
+
+        fn entry_name(&self) -> &EntryName {
+            self.0.as_ref()
+        }
+
+        fn entry_type(&self) -> &EntryType {
+            &EntryType::File
+        }
+
+        fn name_alias(&self) -> Option<&str> {
+            None
+        }
+
+        fn boxed(&self) -> Box<dyn OneEntryNamed> {
+            Box::new(self.clone())
+        }
+    }
+
+    let cat = SharedName(Rc::new(EntryName::Exact("cat.txt".to_string())))
+        .within_path_buf(PathBuf::from("tests/assets"))
+        .find()?;
+
+    assert_eq!(cat, Path::new("tests/assets/cat.txt"));
+    Ok(())
+}
@@ -0,0 +1,115 @@
+mod common;
+
+use common::assets;
+use file_matcher::{
+    FileMatcherError, FileNamed, FilesNamed, FolderNamed, ManyEntriesCopier, MemoryFileSystem,
+    OneEntryCopier, OneEntryMover, Result,
+};
+use std::path::{Path, PathBuf};
+
+#[test]
+pub fn memory_find_one_file_exact() -> Result<()> {
+    let file = FileNamed::exact("cat.txt")
+        .within("assets")
+        .with_file_system(assets())
+        .find()?;
+    assert_eq!(file, Path::new("assets/cat.txt"));
+    Ok(())
+}
+
+#[test]
+pub fn memory_find_one_file_wildmatch_error() {
+    let entry = FileNamed::wildmatch("*.txt")
+        .within("assets")
+        .with_file_system(assets());
+    match entry.find() {
+        Err(FileMatcherError::TooMany(_)) => {}
+        other => panic!("Should fail with too many, but got {:?}", other),
+    }
+}
+
+#[test]
+pub fn memory_as_bytes() -> Result<()> {
+    let bytes = FileNamed::regex("d.g\\.txt")
+        .within("assets")
+        .with_file_system(assets())
+        .as_bytes()?;
+    assert_eq!(bytes, b"woof");
+    Ok(())
+}
+
+#[test]
+pub fn memory_many_recursive_sorted() -> Result<()> {
+    let files = FilesNamed::wildmatch("*.txt")
+        .within("assets")
+        .with_file_system(assets())
+        .recursive()
+        .sorted()
+        .find()?;
+    assert_eq!(
+        files,
+        vec![
+            PathBuf::from("assets/cat/file.txt"),
+            PathBuf::from("assets/cat.txt"),
+            PathBuf::from("assets/dog.txt"),
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+pub fn memory_copy_one_file_alias() -> Result<()> {
+    let file_system = assets();
+
+    let copied = FileNamed::exact("cat.txt")
+        .alias("kitty.txt")
+        .within("assets")
+        .with_file_system(file_system.clone())
+        .copy("destination")?;
+
+    assert_eq!(copied, Path::new("destination/kitty.txt"));
+    assert_eq!(file_system.read("destination/kitty.txt")?, b"meow");
+    assert!(file_system.contains("assets/cat.txt"));
+    Ok(())
+}
+
+#[test]
+pub fn memory_copy_many_nested_files_with_same_name() -> Result<()> {
+    let file_system = MemoryFileSystem::new()
+        .with_file("assets/a/x.txt", "meow")
+        .with_file("assets/b/x.txt", "woof");
+
+    let copies = FilesNamed::exact("x.txt")
+        .within("assets")
+        .with_file_system(file_system.clone())
+        .recursive()
+        .sorted()
+        .copy("destination")?;
+
+    assert_eq!(
+        copies,
+        vec![
+            PathBuf::from("destination/a/x.txt"),
+            PathBuf::from("destination/b/x.txt")
+        ]
+    );
+    assert_eq!(file_system.read("destination/a/x.txt")?, b"meow");
+    assert_eq!(file_system.read("destination/b/x.txt")?, b"woof");
+    Ok(())
+}
+
+#[test]
+pub fn memory_move_one_folder() -> Result<()> {
+    let file_system = assets();
+
+    let moved = FolderNamed::exact("cat")
+        .within("assets")
+        .with_file_system(file_system.clone())
+        .r#move("destination")?;
+
+    assert_eq!(moved, Path::new("destination/cat"));
+    assert_eq!(file_system.read("destination/cat/file.txt")?, b"purr");
+    assert!(!file_system.contains("assets/cat"));
+    assert!(!file_system.contains("assets/cat/file.txt"));
+    Ok(())
+}
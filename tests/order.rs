@@ -0,0 +1,79 @@
+extern crate tempdir;
+
+use file_matcher::{EntriesOrder, FilesNamed, Result};
+use std::ffi::OsString;
+use std::path::PathBuf;
+use tempdir::TempDir;
+
+fn file_names(files: &[PathBuf]) -> Vec<OsString> {
+    files
+        .iter()
+        .map(|each| each.file_name().unwrap().to_os_string())
+        .collect()
+}
+
+#[test]
+pub fn many_sorted_by_name() -> Result<()> {
+    let files = FilesNamed::wildmatch("*.txt")
+        .within("tests/assets")
+        .recursive()
+        .sorted_by(EntriesOrder::Name)
+        .find()?;
+
+    assert_eq!(file_names(&files), vec!["cat.txt", "dog.txt", "file.txt"]);
+    Ok(())
+}
+
+#[test]
+pub fn many_sorted_by_depth() -> Result<()> {
+    let files = FilesNamed::wildmatch("*.txt")
+        .within("tests/assets")
+        .recursive()
+        .sorted_by(EntriesOrder::Depth)
+        .find()?;
+
+    assert_eq!(file_names(&files), vec!["cat.txt", "dog.txt", "file.txt"]);
+    Ok(())
+}
+
+#[test]
+pub fn many_sorted_by_natural_name() -> Result<()> {
+    let tmp_dir = TempDir::new("test")?;
+    for name in ["file10.txt", "file2.txt", "file1.txt", "file02.txt"] {
+        std::fs::write(tmp_dir.path().join(name), "")?;
+    }
+
+    let files = FilesNamed::wildmatch("file*.txt")
+        .within(tmp_dir.path())
+        .sorted_by(EntriesOrder::NaturalName)
+        .find()?;
+
+    assert_eq!(
+        file_names(&files),
+        vec!["file1.txt", "file2.txt", "file02.txt", "file10.txt"]
+    );
+
+    tmp_dir.close()?;
+    Ok(())
+}
+
+#[test]
+pub fn many_sorted_by_size() -> Result<()> {
+    let tmp_dir = TempDir::new("test")?;
+    std::fs::write(tmp_dir.path().join("big.txt"), "big file")?;
+    std::fs::write(tmp_dir.path().join("small.txt"), "small")?;
+    std::fs::write(tmp_dir.path().join("empty.txt"), "")?;
+
+    let files = FilesNamed::wildmatch("*.txt")
+        .within(tmp_dir.path())
+        .sorted_by(EntriesOrder::Size)
+        .find()?;
+
+    assert_eq!(
+        file_names(&files),
+        vec!["empty.txt", "small.txt", "big.txt"]
+    );
+
+    tmp_dir.close()?;
+    Ok(())
+}
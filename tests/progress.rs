@@ -0,0 +1,131 @@
+#![cfg(all(feature = "copier", feature = "mover"))]
+
+extern crate tempdir;
+
+mod common;
+
+use file_matcher::{
+    CopyProgress, FileNamed, FilesNamed, FolderNamed, ManyEntriesCopier, MemoryFileSystem,
+    OneEntryCopier, OneEntryMover, Result,
+};
+use std::path::{Path, PathBuf};
+use tempdir::TempDir;
+
+fn assets() -> MemoryFileSystem {
+    common::assets().with_file("assets/cat/image.bin", vec![7u8; 200 * 1024])
+}
+
+#[test]
+pub fn copy_file_with_progress() -> Result<()> {
+    let file_system = assets();
+    let mut reports: Vec<CopyProgress> = vec![];
+
+    let copy = FileNamed::exact("image.bin")
+        .within("assets/cat")
+        .with_file_system(file_system.clone())
+        .copy_with_progress("destination", |progress| reports.push(progress.clone()))?;
+
+    assert_eq!(copy, PathBuf::from("destination/image.bin"));
+    assert_eq!(file_system.read(&copy)?.len(), 200 * 1024);
+
+    assert!(reports.len() > 2);
+    assert!(reports.iter().all(|each| each.total_bytes() == 200 * 1024
+        && each.current_file() == Path::new("assets/cat/image.bin")));
+    assert!(reports
+        .windows(2)
+        .all(|each| each[0].copied_bytes() <= each[1].copied_bytes()));
+    assert_eq!(reports.last().unwrap().copied_bytes(), 200 * 1024);
+    Ok(())
+}
+
+#[test]
+pub fn copy_folder_with_progress() -> Result<()> {
+    let file_system = assets();
+    let mut files: Vec<PathBuf> = vec![];
+    let mut last: Option<CopyProgress> = None;
+
+    FolderNamed::exact("cat")
+        .within("assets")
+        .with_file_system(file_system.clone())
+        .copy_with_progress("destination", |progress| {
+            if files.last().map(|each| each.as_path()) != Some(progress.current_file()) {
+                files.push(progress.current_file().to_path_buf());
+            }
+            last = Some(progress.clone());
+        })?;
+
+    files.sort();
+    assert_eq!(
+        files,
+        vec![
+            PathBuf::from("assets/cat/file.txt"),
+            PathBuf::from("assets/cat/image.bin")
+        ]
+    );
+    let last = last.unwrap();
+    assert_eq!(last.total_bytes(), 200 * 1024 + 4);
+    assert_eq!(last.copied_bytes(), last.total_bytes());
+    assert_eq!(file_system.read("destination/cat/file.txt")?, b"purr");
+    Ok(())
+}
+
+#[test]
+pub fn copy_many_with_progress() -> Result<()> {
+    let file_system = assets();
+    let mut last: Option<CopyProgress> = None;
+
+    let copies = FilesNamed::wildmatch("*.txt")
+        .within("assets")
+        .with_file_system(file_system.clone())
+        .copy_with_progress("copies", |progress| last = Some(progress.clone()))?;
+
+    assert_eq!(copies.len(), 2);
+    let last = last.unwrap();
+    assert_eq!(last.total_bytes(), 8);
+    assert_eq!(last.copied_bytes(), 8);
+    Ok(())
+}
+
+#[cfg(unix)]
+#[test]
+pub fn copy_and_move_with_progress_keep_permissions() -> Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let tmp_dir = TempDir::new("test")?;
+    let tool = tmp_dir.path().join("tool");
+    std::fs::write(&tool, "#!/bin/sh")?;
+    std::fs::set_permissions(&tool, std::fs::Permissions::from_mode(0o755))?;
+    let destination = tmp_dir.path().join("destination");
+    std::fs::create_dir(&destination)?;
+    let mode = |path: &Path| -> Result<u32> { Ok(std::fs::metadata(path)?.permissions().mode()) };
+
+    let copy = FileNamed::exact("tool")
+        .alias("copy")
+        .within(tmp_dir.path())
+        .copy_with_progress(&destination, |_| {})?;
+    let moved = FileNamed::exact("tool")
+        .within(tmp_dir.path())
+        .move_with_progress(&destination, |_| {})?;
+
+    assert_eq!(mode(&copy)? & 0o777, 0o755);
+    assert_eq!(mode(&moved)? & 0o777, 0o755);
+    Ok(())
+}
+
+#[test]
+pub fn move_file_with_progress() -> Result<()> {
+    let file_system = assets();
+    let mut last: Option<CopyProgress> = None;
+
+    let moved = FileNamed::exact("cat.txt")
+        .alias("kitty.txt")
+        .within("assets")
+        .with_file_system(file_system.clone())
+        .move_with_progress("destination", |progress| last = Some(progress.clone()))?;
+
+    assert_eq!(moved, PathBuf::from("destination/kitty.txt"));
+    assert_eq!(file_system.read(&moved)?, b"meow");
+    assert!(!file_system.contains("assets/cat.txt"));
+    assert_eq!(last.unwrap().copied_bytes(), 4);
+    Ok(())
+}
@@ -0,0 +1,180 @@
+#![cfg(feature = "remover")]
+
+extern crate tempdir;
+
+mod common;
+
+use common::assets;
+use file_matcher::{
+    EntryName, EntryType, FileMatcherError, FileNamed, FilesNamed, FolderNamed, ManyEntries,
+    ManyEntriesNamed, ManyEntriesRemover, OneEntryRemover, Operation, Plan, Result,
+};
+use std::path::PathBuf;
+use tempdir::TempDir;
+
+#[derive(Debug, Clone)]
+struct EntriesNamed(EntryName, EntryType);
+
+impl ManyEntriesNamed for EntriesNamed {
+    fn within_path_buf(&self, directory: PathBuf) -> ManyEntries {
+        ManyEntries::new(self.boxed(), directory)
+    }
+
+    fn entry_name(&self) -> &EntryName {
+        &self.0
+    }
+
+    fn entry_type(&self) -> &EntryType {
+        &self.1
+    }
+
+    fn name_alias(&self) -> Option<&str> {
+        None
+    }
+
+    fn boxed(&self) -> Box<dyn ManyEntriesNamed> {
+        Box::new(self.clone())
+    }
+}
+
+#[test]
+pub fn remove_one_file() -> Result<()> {
+    let file_system = assets();
+
+    let removed = FileNamed::exact("cat.txt")
+        .within("assets")
+        .with_file_system(file_system.clone())
+        .remove()?;
+
+    assert_eq!(removed, PathBuf::from("assets/cat.txt"));
+    assert!(!file_system.contains("assets/cat.txt"));
+    assert!(file_system.contains("assets/dog.txt"));
+    Ok(())
+}
+
+#[test]
+pub fn remove_one_folder_recursively() -> Result<()> {
+    let file_system = assets();
+
+    FolderNamed::exact("cat")
+        .within("assets")
+        .with_file_system(file_system.clone())
+        .remove()?;
+
+    assert!(!file_system.contains("assets/cat"));
+    assert!(!file_system.contains("assets/cat/file.txt"));
+    assert!(file_system.contains("assets/cat.txt"));
+    Ok(())
+}
+
+#[test]
+pub fn remove_one_dry_run() -> Result<()> {
+    let file_system = assets();
+
+    let removed = FolderNamed::exact("cat")
+        .within("assets")
+        .with_file_system(file_system.clone())
+        .remove_dry_run()?;
+
+    assert_eq!(
+        removed.operations(),
+        &[Operation::RemoveFolder(PathBuf::from("assets/cat"))]
+    );
+    assert!(file_system.contains("assets/cat/file.txt"));
+    Ok(())
+}
+
+#[test]
+pub fn remove_many_files() -> Result<()> {
+    let file_system = assets();
+
+    let removed = FilesNamed::wildmatch("*.txt")
+        .within("assets")
+        .with_file_system(file_system.clone())
+        .recursive()
+        .sorted()
+        .remove()?;
+
+    assert_eq!(
+        removed,
+        vec![
+            PathBuf::from("assets/cat/file.txt"),
+            PathBuf::from("assets/cat.txt"),
+            PathBuf::from("assets/dog.txt"),
+        ]
+    );
+    assert!(!file_system.contains("assets/cat/file.txt"));
+    assert!(file_system.contains("assets/cat"));
+    Ok(())
+}
+
+#[test]
+pub fn remove_many_skips_entries_within_removed_folders_dry_run() -> Result<()> {
+    let file_system = assets();
+
+    let removed = EntriesNamed(EntryName::Wildmatch("*".to_owned()), EntryType::Any)
+        .within_path_buf(PathBuf::from("assets"))
+        .with_file_system(file_system.clone())
+        .recursive()
+        .sorted()
+        .remove_dry_run()?;
+
+    assert_eq!(
+        removed,
+        Plan::from(vec![
+            Operation::RemoveFolder(PathBuf::from("assets/cat")),
+            Operation::RemoveFile(PathBuf::from("assets/cat.txt")),
+            Operation::RemoveFile(PathBuf::from("assets/dog.txt")),
+        ])
+    );
+    Ok(())
+}
+
+#[test]
+pub fn remove_refuses_search_folder() -> Result<()> {
+    let tmp_dir = TempDir::new("test").unwrap();
+    let directory = tmp_dir.path().join("cat");
+    std::fs::create_dir(&directory)?;
+    std::fs::write(directory.join("file.txt"), "purr")?;
+
+    let result = FolderNamed::exact("..").within(&directory).remove();
+
+    match result {
+        Err(FileMatcherError::RemovingSearchFolder(path)) => {
+            assert_eq!(path, directory.join(".."))
+        }
+        other => panic!("Expected a search folder error, got {:?}", other),
+    }
+    assert!(directory.join("file.txt").exists());
+    Ok(())
+}
+
+#[test]
+pub fn refuse_to_remove_search_folder_spelled_absolute() -> Result<()> {
+    let current_folder = std::env::current_dir()?;
+
+    let result = FolderNamed::exact(current_folder.to_str().unwrap())
+        .within(".")
+        .remove_dry_run();
+
+    match result {
+        Err(FileMatcherError::RemovingSearchFolder(path)) => {
+            assert_eq!(path, current_folder)
+        }
+        other => panic!("Expected a search folder error, got {:?}", other),
+    }
+    Ok(())
+}
+
+#[test]
+pub fn remove_file_on_disk() -> Result<()> {
+    let tmp_dir = TempDir::new("test").unwrap();
+    std::fs::write(tmp_dir.path().join("cat.txt"), "meow")?;
+
+    FileNamed::exact("cat.txt")
+        .within(tmp_dir.path())
+        .remove()?;
+
+    assert!(!tmp_dir.path().join("cat.txt").exists());
+    Ok(())
+}
@@ -0,0 +1,212 @@
+#![cfg(feature = "syncer")]
+
+extern crate tempdir;
+
+use file_matcher::{
+    FileNamed, FilesNamed, FolderNamed, ManyEntriesSyncer, MemoryFileSystem, OneEntrySyncer,
+    Operation, Result, SyncOptions,
+};
+use std::path::PathBuf;
+use std::time::SystemTime;
+use tempdir::TempDir;
+
+fn plugins() -> MemoryFileSystem {
+    MemoryFileSystem::new()
+        .with_file("plugins/cat/cat.dll", "meow")
+        .with_file("plugins/cat/config/cat.toml", "volume = 11")
+        .with_file("plugins/dog.dll", "woof")
+        .with_folder("install")
+}
+
+fn paths(paths: &[&str]) -> Vec<PathBuf> {
+    paths.iter().map(PathBuf::from).collect()
+}
+
+#[test]
+pub fn sync_folder_copies_only_changes() -> Result<()> {
+    let file_system = plugins();
+    let cat = FolderNamed::exact("cat")
+        .within("plugins")
+        .with_file_system(file_system.clone());
+
+    let summary = cat.sync("install")?;
+    assert_eq!(
+        summary.added(),
+        paths(&[
+            "install/cat",
+            "install/cat/cat.dll",
+            "install/cat/config",
+            "install/cat/config/cat.toml",
+        ])
+    );
+    assert_eq!(
+        file_system.read("install/cat/config/cat.toml")?,
+        b"volume = 11"
+    );
+
+    assert!(cat.sync("install")?.is_empty());
+
+    file_system.add_file("plugins/cat/cat.dll", "purr");
+    let summary = cat.sync("install")?;
+    assert!(summary.added().is_empty());
+    assert_eq!(summary.updated(), paths(&["install/cat/cat.dll"]));
+    assert_eq!(file_system.read("install/cat/cat.dll")?, b"purr");
+    Ok(())
+}
+
+#[test]
+pub fn sync_keeps_extraneous_entries_by_default() -> Result<()> {
+    let file_system = plugins().with_file("install/cat/old.dll", "hiss");
+
+    let summary = FolderNamed::exact("cat")
+        .within("plugins")
+        .with_file_system(file_system.clone())
+        .sync("install")?;
+
+    assert!(summary.removed().is_empty());
+    assert!(file_system.contains("install/cat/old.dll"));
+    Ok(())
+}
+
+#[test]
+pub fn sync_deletes_extraneous_entries() -> Result<()> {
+    let file_system = plugins()
+        .with_file("install/cat/old.dll", "hiss")
+        .with_file("install/cat/config/old/cat.toml", "volume = 0");
+
+    let summary = FolderNamed::exact("cat")
+        .within("plugins")
+        .with_file_system(file_system.clone())
+        .sync_with_options("install", SyncOptions::new().delete_extraneous())?;
+
+    assert_eq!(
+        summary.removed(),
+        paths(&["install/cat/config/old", "install/cat/old.dll"])
+    );
+    assert!(!file_system.contains("install/cat/old.dll"));
+    assert!(!file_system.contains("install/cat/config/old"));
+    assert!(file_system.contains("install/cat/config/cat.toml"));
+    Ok(())
+}
+
+#[test]
+pub fn sync_many_entries_deletes_entries_not_found() -> Result<()> {
+    let file_system = plugins().with_file("install/bird.dll", "tweet");
+    let dlls = FilesNamed::wildmatch("*.dll")
+        .within("plugins")
+        .with_file_system(file_system.clone());
+
+    let summary = dlls.sync_with_options("install", SyncOptions::new().delete_extraneous())?;
+
+    assert_eq!(summary.added(), paths(&["install/dog.dll"]));
+    assert_eq!(summary.removed(), paths(&["install/bird.dll"]));
+    assert!(dlls.sync("install")?.is_empty());
+    Ok(())
+}
+
+#[test]
+pub fn sync_many_nested_files_with_same_name() -> Result<()> {
+    let file_system = MemoryFileSystem::new()
+        .with_file("plugins/cat/plugin.dll", "meow")
+        .with_file("plugins/dog/plugin.dll", "woof");
+    let dlls = FilesNamed::exact("plugin.dll")
+        .within("plugins")
+        .with_file_system(file_system.clone())
+        .recursive();
+
+    let summary = dlls.sync_with_options("install", SyncOptions::new().delete_extraneous())?;
+
+    let mut added = summary.added().to_vec();
+    added.sort();
+    assert_eq!(
+        added,
+        paths(&["install/cat/plugin.dll", "install/dog/plugin.dll"])
+    );
+    assert_eq!(file_system.read("install/cat/plugin.dll")?, b"meow");
+    assert_eq!(file_system.read("install/dog/plugin.dll")?, b"woof");
+    assert!(dlls
+        .sync_with_options("install", SyncOptions::new().delete_extraneous())?
+        .is_empty());
+    assert!(dlls.sync_dry_run("install", SyncOptions::new())?.is_empty());
+    Ok(())
+}
+
+#[test]
+pub fn sync_replaces_entry_of_another_type() -> Result<()> {
+    let file_system = plugins().with_file("install/cat", "not a folder");
+
+    let summary = FolderNamed::exact("cat")
+        .within("plugins")
+        .with_file_system(file_system.clone())
+        .sync("install")?;
+
+    assert_eq!(summary.updated(), paths(&["install/cat"]));
+    assert_eq!(file_system.read("install/cat/cat.dll")?, b"meow");
+    Ok(())
+}
+
+#[test]
+pub fn sync_dry_run() -> Result<()> {
+    let file_system = plugins().with_file("install/bird.dll", "tweet");
+
+    let plan = FileNamed::exact("dog.dll")
+        .within("plugins")
+        .with_file_system(file_system.clone())
+        .sync_dry_run("install", SyncOptions::new().delete_extraneous())?;
+
+    assert_eq!(
+        plan.operations(),
+        &[Operation::CopyFile {
+            from: PathBuf::from("plugins/dog.dll"),
+            to: PathBuf::from("install/dog.dll"),
+        }]
+    );
+    assert!(!file_system.contains("install/dog.dll"));
+    Ok(())
+}
+
+#[cfg(feature = "sha256")]
+#[test]
+pub fn sync_comparing_checksums() -> Result<()> {
+    use file_matcher::{Checksum, FileSystem};
+    use std::time::Duration;
+
+    let file_system = plugins()
+        .with_file("install/cat/cat.dll", "meow")
+        .with_file("install/cat/config/cat.toml", "volume = 00");
+    file_system.set_times(
+        "install/cat/cat.dll".as_ref(),
+        None,
+        Some(SystemTime::UNIX_EPOCH + Duration::from_secs(1_500_000_000)),
+    )?;
+
+    let summary = FolderNamed::exact("cat")
+        .within("plugins")
+        .with_file_system(file_system.clone())
+        .sync_with_options(
+            "install",
+            SyncOptions::new().compare_checksums(Checksum::Sha256),
+        )?;
+
+    assert!(summary.added().is_empty());
+    assert_eq!(summary.updated(), paths(&["install/cat/config/cat.toml"]));
+    Ok(())
+}
+
+#[test]
+pub fn sync_on_disk_preserves_modification_times() -> Result<()> {
+    let tmp_dir = TempDir::new("test")?;
+
+    let assets = FolderNamed::exact("cat").within("tests/assets");
+    let summary = assets.sync(tmp_dir.path())?;
+    assert!(!summary.added().is_empty());
+
+    let modified =
+        |path: PathBuf| -> Result<SystemTime> { Ok(std::fs::metadata(path)?.modified()?) };
+    assert_eq!(
+        modified(tmp_dir.path().join("cat/file.txt"))?,
+        modified(PathBuf::from("tests/assets/cat/file.txt"))?
+    );
+    assert!(assets.sync(tmp_dir.path())?.is_empty());
+    Ok(())
+}
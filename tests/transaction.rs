@@ -0,0 +1,273 @@
+#![cfg(feature = "transaction")]
+
+extern crate tempdir;
+
+mod common;
+
+use common::{entries, FailingFileSystem, Failure};
+use file_matcher::{
+    FileMatcherError, FileNamed, FilesNamed, FolderNamed, MemoryFileSystem, Operation, Result,
+    Transaction,
+};
+use std::path::{Path, PathBuf};
+use tempdir::TempDir;
+
+fn assets() -> MemoryFileSystem {
+    common::assets().with_file("install/cat.txt", "old meow")
+}
+
+#[test]
+pub fn commit_keeps_changes() -> Result<()> {
+    let file_system = assets();
+
+    let mut transaction = Transaction::new();
+    transaction.copy(
+        &FileNamed::exact("cat.txt")
+            .within("assets")
+            .with_file_system(file_system.clone()),
+        "install",
+    )?;
+    transaction.r#move(
+        &FolderNamed::exact("cat")
+            .within("assets")
+            .with_file_system(file_system.clone()),
+        "install",
+    )?;
+    transaction.remove(
+        &FileNamed::exact("dog.txt")
+            .within("assets")
+            .with_file_system(file_system.clone()),
+    )?;
+    let completed = transaction.commit()?;
+
+    assert_eq!(completed.len(), 3);
+    assert_eq!(
+        entries(&file_system, Path::new("assets"))?,
+        vec![PathBuf::from("assets/cat.txt")]
+    );
+    assert_eq!(
+        entries(&file_system, Path::new("install"))?,
+        vec![
+            PathBuf::from("install/cat"),
+            PathBuf::from("install/cat/file.txt"),
+            PathBuf::from("install/cat.txt"),
+        ]
+    );
+    assert_eq!(file_system.read("install/cat.txt")?, b"meow");
+    Ok(())
+}
+
+#[test]
+pub fn rollback_undoes_changes() -> Result<()> {
+    let file_system = assets();
+    let before = entries(&file_system, Path::new("/"))?;
+
+    let mut transaction = Transaction::new();
+    transaction.copy(
+        &FileNamed::exact("cat.txt")
+            .within("assets")
+            .with_file_system(file_system.clone()),
+        "install",
+    )?;
+    transaction.move_many(
+        &FilesNamed::wildmatch("*.txt")
+            .within("assets")
+            .with_file_system(file_system.clone()),
+        "backup/text",
+    )?;
+    transaction.remove(
+        &FolderNamed::exact("cat")
+            .within("assets")
+            .with_file_system(file_system.clone()),
+    )?;
+    transaction.rollback()?;
+
+    assert_eq!(entries(&file_system, Path::new("/"))?, before);
+    assert_eq!(file_system.read("install/cat.txt")?, b"old meow");
+    assert_eq!(file_system.read("assets/cat/file.txt")?, b"purr");
+    Ok(())
+}
+
+#[test]
+pub fn failed_step_rolls_back_completed_steps() -> Result<()> {
+    let file_system = assets();
+    let before = entries(&file_system, Path::new("/"))?;
+
+    let result = Transaction::run(|transaction| {
+        transaction.move_many(
+            &FilesNamed::wildmatch("*.txt")
+                .within("assets")
+                .with_file_system(file_system.clone()),
+            "install",
+        )?;
+        transaction.remove(
+            &FileNamed::exact("bird.txt")
+                .within("assets")
+                .with_file_system(file_system.clone()),
+        )
+    });
+
+    assert!(matches!(result, Err(FileMatcherError::NotExists(_))));
+    assert_eq!(entries(&file_system, Path::new("/"))?, before);
+    assert_eq!(file_system.read("install/cat.txt")?, b"old meow");
+    Ok(())
+}
+
+#[test]
+pub fn move_many_nested_files_with_same_name() -> Result<()> {
+    let file_system = MemoryFileSystem::new()
+        .with_file("src/a/x.txt", "meow")
+        .with_file("src/b/x.txt", "woof");
+
+    let moved = Transaction::run(|transaction| {
+        transaction.move_many(
+            &FilesNamed::exact("x.txt")
+                .within("src")
+                .with_file_system(file_system.clone())
+                .recursive()
+                .sorted(),
+            "dst",
+        )
+    })?;
+
+    assert_eq!(
+        moved,
+        vec![PathBuf::from("dst/a/x.txt"), PathBuf::from("dst/b/x.txt")]
+    );
+    assert_eq!(file_system.read("dst/a/x.txt")?, b"meow");
+    assert_eq!(file_system.read("dst/b/x.txt")?, b"woof");
+    assert!(!file_system.contains("src/a/x.txt"));
+    assert!(!file_system.contains("src/b/x.txt"));
+    Ok(())
+}
+
+#[test]
+pub fn dropped_transaction_rolls_back() -> Result<()> {
+    let file_system = assets();
+
+    {
+        let mut transaction = Transaction::new();
+        transaction.remove(
+            &FileNamed::exact("dog.txt")
+                .within("assets")
+                .with_file_system(file_system.clone()),
+        )?;
+        assert!(!file_system.contains("assets/dog.txt"));
+    }
+
+    assert_eq!(file_system.read("assets/dog.txt")?, b"woof");
+    Ok(())
+}
+
+#[test]
+pub fn completed_operations() -> Result<()> {
+    let file_system = assets();
+
+    let mut transaction = Transaction::new();
+    transaction.copy_many(
+        &FilesNamed::exact("dog.txt")
+            .within("assets")
+            .with_file_system(file_system.clone()),
+        "install",
+    )?;
+
+    assert_eq!(
+        transaction.completed().operations(),
+        &[
+            Operation::CreateFolder(PathBuf::from("install")),
+            Operation::CopyFile {
+                from: PathBuf::from("assets/dog.txt"),
+                to: PathBuf::from("install/dog.txt"),
+            },
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+pub fn rollback_keeps_existing_content_of_folder() -> Result<()> {
+    let file_system = assets().with_file("install/cat/old.txt", "old purr");
+
+    let mut transaction = Transaction::new();
+    let copy = transaction.copy(
+        &FolderNamed::exact("cat")
+            .within("assets")
+            .with_file_system(file_system.clone()),
+        "install/cat",
+    )?;
+    assert_eq!(copy, PathBuf::from("install/cat/cat"));
+    transaction.rollback()?;
+
+    assert_eq!(
+        entries(&file_system, Path::new("install"))?,
+        vec![
+            PathBuf::from("install/cat"),
+            PathBuf::from("install/cat/old.txt"),
+            PathBuf::from("install/cat.txt"),
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+pub fn rollback_restores_partially_removed_moved_folder() -> Result<()> {
+    let file_system = assets().with_file("assets/cat/image.txt", "image");
+
+    let mut transaction = Transaction::new();
+    let result = transaction.r#move(
+        &FolderNamed::exact("cat")
+            .within("assets")
+            .with_file_system(FailingFileSystem::new(
+                file_system.clone(),
+                Failure::PartialRemoval {
+                    folder: "assets/cat",
+                    removed: "file.txt",
+                },
+            )),
+        "install",
+    );
+    assert!(result.is_err());
+    transaction.rollback()?;
+
+    assert_eq!(
+        entries(&file_system, Path::new("assets"))?,
+        vec![
+            PathBuf::from("assets/cat"),
+            PathBuf::from("assets/cat/file.txt"),
+            PathBuf::from("assets/cat/image.txt"),
+            PathBuf::from("assets/cat.txt"),
+            PathBuf::from("assets/dog.txt"),
+        ]
+    );
+    assert_eq!(file_system.read("assets/cat/file.txt")?, b"purr");
+    assert_eq!(
+        entries(&file_system, Path::new("install"))?,
+        vec![PathBuf::from("install/cat.txt")]
+    );
+    Ok(())
+}
+
+#[test]
+pub fn rollback_on_disk() -> Result<()> {
+    let tmp_dir = TempDir::new("test")?;
+    let assets = tmp_dir.path().join("assets");
+    std::fs::create_dir_all(assets.join("cat"))?;
+    std::fs::write(assets.join("cat.txt"), "meow")?;
+    std::fs::write(assets.join("cat/file.txt"), "purr")?;
+
+    let result = Transaction::run(|transaction| {
+        transaction.r#move(
+            &FolderNamed::exact("cat").within(&assets),
+            tmp_dir.path().join("install"),
+        )?;
+        transaction.remove(&FileNamed::exact("cat.txt").within(&assets))?;
+        transaction.copy(&FileNamed::exact("dog.txt").within(&assets), tmp_dir.path())
+    });
+
+    assert!(result.is_err());
+    assert_eq!(std::fs::read(assets.join("cat.txt"))?, b"meow");
+    assert_eq!(std::fs::read(assets.join("cat/file.txt"))?, b"purr");
+    assert!(!tmp_dir.path().join("install").exists());
+    assert_eq!(std::fs::read_dir(&assets)?.count(), 2);
+    Ok(())
+}
@@ -0,0 +1,155 @@
+extern crate tempdir;
+
+use file_matcher::{
+    FileMatcherError, FileNamed, FilesNamed, FolderNamed, MemoryFileSystem, Result,
+};
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::Duration;
+use tempdir::TempDir;
+
+fn create_later(path: impl AsRef<Path>) -> thread::JoinHandle<()> {
+    let path = path.as_ref().to_path_buf();
+    thread::spawn(move || {
+        thread::sleep(Duration::from_millis(200));
+        std::fs::write(path, "meow").unwrap();
+    })
+}
+
+#[test]
+pub fn wait_for_file_created_later() -> Result<()> {
+    let tmp_dir = TempDir::new("test")?;
+    let producer = create_later(tmp_dir.path().join("cat.txt"));
+
+    let cat = FileNamed::exact("cat.txt")
+        .within(tmp_dir.path())
+        .wait_for(Duration::from_secs(5))?;
+
+    producer.join().unwrap();
+    assert_eq!(cat, tmp_dir.path().join("cat.txt"));
+    Ok(())
+}
+
+#[test]
+pub fn wait_for_existing_file() -> Result<()> {
+    let cat = FileNamed::wildmatch("cat*")
+        .within("tests/assets")
+        .wait_for(Duration::ZERO)?;
+
+    assert_eq!(cat, PathBuf::from("tests/assets/cat.txt"));
+    Ok(())
+}
+
+#[test]
+pub fn wait_for_file_without_deadline() -> Result<()> {
+    let tmp_dir = TempDir::new("test")?;
+    let producer = create_later(tmp_dir.path().join("cat.txt"));
+
+    let cat = FileNamed::exact("cat.txt")
+        .within(tmp_dir.path())
+        .wait_for(Duration::MAX)?;
+
+    producer.join().unwrap();
+    assert_eq!(cat, tmp_dir.path().join("cat.txt"));
+    Ok(())
+}
+
+#[test]
+pub fn wait_for_file_within_folder_created_later() -> Result<()> {
+    let tmp_dir = TempDir::new("test")?;
+    let folder = tmp_dir.path().join("build");
+    let producer = thread::spawn({
+        let folder = folder.clone();
+        move || {
+            thread::sleep(Duration::from_millis(200));
+            std::fs::create_dir(&folder).unwrap();
+            std::fs::write(folder.join("cat.txt"), "meow").unwrap();
+        }
+    });
+
+    let cat = FileNamed::wildmatch("*.txt")
+        .within(&folder)
+        .wait_for(Duration::from_secs(5))?;
+
+    producer.join().unwrap();
+    assert_eq!(cat, folder.join("cat.txt"));
+    Ok(())
+}
+
+#[test]
+pub fn wait_for_file_times_out() -> Result<()> {
+    let result = FileNamed::exact("cat.txt")
+        .within("assets")
+        .with_file_system(MemoryFileSystem::new().with_file("assets/dog.txt", "woof"))
+        .wait_for(Duration::from_millis(100));
+
+    match result {
+        Err(FileMatcherError::Timeout(timeout, last)) => {
+            assert_eq!(timeout, Duration::from_millis(100));
+            assert!(matches!(*last, FileMatcherError::NotExists(_)));
+        }
+        other => panic!("Expected a timeout, got {:?}", other),
+    }
+    Ok(())
+}
+
+#[test]
+pub fn wait_for_file_times_out_with_too_many() -> Result<()> {
+    let result = FolderNamed::any(vec!["cat", "dog"])
+        .within("assets")
+        .with_file_system(
+            MemoryFileSystem::new()
+                .with_folder("assets/cat")
+                .with_folder("assets/dog"),
+        )
+        .wait_for(Duration::from_millis(100));
+
+    match result {
+        Err(FileMatcherError::Timeout(_, last)) => {
+            assert!(matches!(*last, FileMatcherError::TooMany(_)));
+        }
+        other => panic!("Expected a timeout, got {:?}", other),
+    }
+    Ok(())
+}
+
+#[test]
+pub fn wait_for_at_least_files() -> Result<()> {
+    let tmp_dir = TempDir::new("test")?;
+    std::fs::write(tmp_dir.path().join("cat.txt"), "meow")?;
+    let producer = create_later(tmp_dir.path().join("kitty.txt"));
+
+    let files = FilesNamed::wildmatch("*.txt")
+        .within(tmp_dir.path())
+        .sorted()
+        .wait_for_at_least(2, Duration::from_secs(5))?;
+
+    producer.join().unwrap();
+    assert_eq!(
+        files,
+        vec![
+            tmp_dir.path().join("cat.txt"),
+            tmp_dir.path().join("kitty.txt")
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+pub fn wait_for_at_least_files_times_out_with_found_files() -> Result<()> {
+    let result = FilesNamed::wildmatch("*.txt")
+        .within("assets")
+        .with_file_system(MemoryFileSystem::new().with_file("assets/cat.txt", "meow"))
+        .wait_for_at_least(2, Duration::from_millis(100));
+
+    match result {
+        Err(FileMatcherError::Timeout(_, last)) => match *last {
+            FileMatcherError::WrongCount(_, found) => {
+                assert_eq!(found, vec![PathBuf::from("assets/cat.txt")]);
+            }
+            other => panic!("Expected a wrong count, got {:?}", other),
+        },
+        other => panic!("Expected a timeout, got {:?}", other),
+    }
+    Ok(())
+}
@@ -0,0 +1,123 @@
+#![cfg(feature = "watch")]
+
+extern crate tempdir;
+
+use file_matcher::{EntriesWatcher, FileNamed, FilesNamed, FolderNamed, Result, WatchEvent};
+use std::time::Duration;
+use tempdir::TempDir;
+
+fn next_event(watcher: &EntriesWatcher) -> Result<Option<WatchEvent>> {
+    watcher.recv_timeout(Duration::from_secs(5))
+}
+
+fn no_more_events(watcher: &EntriesWatcher) -> Result<bool> {
+    Ok(watcher.recv_timeout(Duration::from_millis(200))?.is_none())
+}
+
+#[test]
+pub fn watch_file_created_modified_and_removed() -> Result<()> {
+    let tmp_dir = TempDir::new("test")?;
+    let image = tmp_dir.path().join("GlamorousToolkit.image");
+
+    let watcher = FileNamed::exact("GlamorousToolkit.image")
+        .within(tmp_dir.path())
+        .watch()?;
+
+    std::fs::write(tmp_dir.path().join("GlamorousToolkit.changes"), "ignored")?;
+    std::fs::write(&image, "image")?;
+    assert_eq!(
+        next_event(&watcher)?,
+        Some(WatchEvent::Created(image.clone()))
+    );
+
+    // writing the content is reported as one or more modifications
+    while let Some(event) = watcher.recv_timeout(Duration::from_millis(200))? {
+        assert_eq!(event, WatchEvent::Modified(image.clone()));
+    }
+
+    std::fs::remove_file(&image)?;
+    assert_eq!(next_event(&watcher)?, Some(WatchEvent::Removed(image)));
+    assert!(no_more_events(&watcher)?);
+    Ok(())
+}
+
+#[test]
+pub fn watch_ignores_entries_of_another_type() -> Result<()> {
+    let tmp_dir = TempDir::new("test")?;
+
+    let watcher = FolderNamed::exact("cat").within(tmp_dir.path()).watch()?;
+
+    std::fs::File::create(tmp_dir.path().join("cat"))?;
+    std::fs::remove_file(tmp_dir.path().join("cat"))?;
+    std::fs::create_dir(tmp_dir.path().join("cat"))?;
+
+    assert_eq!(
+        next_event(&watcher)?,
+        Some(WatchEvent::Created(tmp_dir.path().join("cat")))
+    );
+    assert!(no_more_events(&watcher)?);
+    Ok(())
+}
+
+#[test]
+pub fn watch_renamed_entries() -> Result<()> {
+    let tmp_dir = TempDir::new("test")?;
+    std::fs::write(tmp_dir.path().join("cat.txt"), "meow")?;
+
+    let watcher = FilesNamed::wildmatch("*.txt")
+        .within(tmp_dir.path())
+        .watch()?;
+
+    std::fs::rename(
+        tmp_dir.path().join("cat.txt"),
+        tmp_dir.path().join("cat.bak"),
+    )?;
+    assert_eq!(
+        next_event(&watcher)?,
+        Some(WatchEvent::Removed(tmp_dir.path().join("cat.txt")))
+    );
+
+    std::fs::rename(
+        tmp_dir.path().join("cat.bak"),
+        tmp_dir.path().join("kitty.txt"),
+    )?;
+    assert_eq!(
+        next_event(&watcher)?,
+        Some(WatchEvent::Created(tmp_dir.path().join("kitty.txt")))
+    );
+    assert!(no_more_events(&watcher)?);
+    Ok(())
+}
+
+#[test]
+pub fn watch_nested_folders_when_recursive() -> Result<()> {
+    let tmp_dir = TempDir::new("test")?;
+    std::fs::create_dir(tmp_dir.path().join("nested"))?;
+    let nested = tmp_dir.path().join("nested").join("cat.txt");
+
+    let not_recursive = FilesNamed::wildmatch("*.txt")
+        .within(tmp_dir.path())
+        .watch()?;
+    let recursive = FilesNamed::wildmatch("*.txt")
+        .within(tmp_dir.path())
+        .recursive()
+        .watch()?;
+
+    std::fs::File::create(&nested)?;
+
+    assert_eq!(next_event(&recursive)?, Some(WatchEvent::Created(nested)));
+    assert!(no_more_events(&not_recursive)?);
+    Ok(())
+}
+
+#[test]
+pub fn watch_missing_folder_fails() -> Result<()> {
+    let tmp_dir = TempDir::new("test")?;
+
+    let result = FileNamed::exact("cat.txt")
+        .within(tmp_dir.path().join("missing"))
+        .watch();
+
+    assert!(result.is_err());
+    Ok(())
+}